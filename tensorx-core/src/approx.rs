@@ -0,0 +1,83 @@
+//! Floating point classification and approximate comparison.
+
+use crate::element::Float;
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+/// `|a - b| <= atol + rtol * |b|`, the asymmetric test used by numpy.
+fn close<T: Float>(a: T, b: T, rtol: T, atol: T, equal_nan: bool) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return equal_nan && a.is_nan() && b.is_nan();
+    }
+    // Covers equal infinities, whose difference would be NaN.
+    a == b || (a - b).abs() <= atol + rtol * b.abs()
+}
+
+impl<T: Float> Tensor<T> {
+    pub fn isnan(&self) -> Tensor<bool> {
+        self.map(|x| x.is_nan())
+    }
+
+    pub fn isinf(&self) -> Tensor<bool> {
+        self.map(|x| x.is_infinite())
+    }
+
+    pub fn isfinite(&self) -> Tensor<bool> {
+        self.map(|x| x.is_finite())
+    }
+
+    /// Replaces NaN, positive infinity and negative infinity with the given
+    /// values.
+    pub fn nan_to_num(&self, nan: T, posinf: T, neginf: T) -> Self {
+        self.map(|&x| {
+            if x.is_nan() {
+                nan
+            } else if x == T::infinity() {
+                posinf
+            } else if x == T::neg_infinity() {
+                neginf
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Elementwise `|self - other| <= atol + rtol * |other|` after
+    /// broadcasting. Infinities are close only to themselves, and NaNs only
+    /// to each other when `equal_nan` is set.
+    pub fn isclose(&self, other: &Self, rtol: T, atol: T, equal_nan: bool) -> Result<Tensor<bool>> {
+        self.zip_with(other, |&a, &b| close(a, b, rtol, atol, equal_nan))
+    }
+
+    /// Whether every element is close to its counterpart; see
+    /// [`Tensor::isclose`].
+    pub fn allclose(&self, other: &Self, rtol: T, atol: T, equal_nan: bool) -> Result<bool> {
+        Ok(self
+            .isclose(other, rtol, atol, equal_nan)?
+            .iter()
+            .all(|&c| c))
+    }
+
+    /// The multi-index and values of the first element, in logical order,
+    /// that is not close to `expected`. Unlike [`Tensor::isclose`] the shapes
+    /// must match exactly. Used by [`assert_tensor_close!`](crate::assert_tensor_close).
+    pub fn first_mismatch(
+        &self,
+        expected: &Self,
+        rtol: T,
+        atol: T,
+        equal_nan: bool,
+    ) -> Result<Option<(Vec<usize>, T, T)>> {
+        if self.shape != expected.shape {
+            return Err(TensorError::ShapeMismatch {
+                expected: expected.shape.clone(),
+                actual: self.shape.clone(),
+            });
+        }
+        Ok(self
+            .indexed_iter()
+            .zip(expected.iter())
+            .find(|&((_, &a), &b)| !close(a, b, rtol, atol, equal_nan))
+            .map(|((index, &a), &b)| (index, a, b)))
+    }
+}
@@ -0,0 +1,173 @@
+//! BLAS-style kernels that accumulate into an existing output tensor.
+//!
+//! Each routine computes `out = alpha·(product) + beta·out` (or `out +=` for
+//! the level-1 and rank-1 updates) in a single pass, reading strided inputs
+//! in place. A linear layer `x·Wᵀ + b` is one [`gemm`] call into an output
+//! that already holds the bias, with no intermediate tensors.
+//!
+//! As in reference BLAS, a `beta` of zero overwrites the output without
+//! reading it, so NaNs already stored there do not propagate.
+
+use crate::element::Num;
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+/// A matrix operand viewed through an optional transpose.
+struct Operand<'a, T> {
+    tensor: &'a Tensor<T>,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<'a, T: Num> Operand<'a, T> {
+    fn new(tensor: &'a Tensor<T>, trans: bool) -> Result<Self> {
+        let rank = tensor.shape.len();
+        if rank != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: rank,
+            });
+        }
+        let (r, c) = if trans { (1, 0) } else { (0, 1) };
+        Ok(Self {
+            tensor,
+            rows: tensor.shape[r],
+            cols: tensor.shape[c],
+            row_stride: tensor.strides[r],
+            col_stride: tensor.strides[c],
+        })
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self.tensor.data[self.tensor.offset + i * self.row_stride + j * self.col_stride]
+    }
+}
+
+fn ensure_rank<T>(t: &Tensor<T>, rank: usize) -> Result<()> {
+    if t.shape.len() != rank {
+        return Err(TensorError::RankMismatch {
+            expected: rank,
+            actual: t.shape.len(),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_shape<T>(t: &Tensor<T>, expected: &[usize]) -> Result<()> {
+    if t.shape != expected {
+        return Err(TensorError::ShapeMismatch {
+            expected: expected.to_vec(),
+            actual: t.shape.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_len(expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(TensorError::DimensionalMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+/// Scales `out` by `beta`, treating zero as an overwrite.
+fn scale<T: Num>(out: &mut Tensor<T>, beta: T) {
+    if beta == T::zero() {
+        out.map_inplace(|x| *x = T::zero());
+    } else if beta != T::one() {
+        out.map_inplace(|x| *x *= beta);
+    }
+}
+
+/// General matrix multiply: `c = alpha·op(a)·op(b) + beta·c`, where `op`
+/// transposes its argument when the matching `trans_*` flag is set.
+///
+/// `c` must already have shape `[m, n]` for an `[m, k]` `op(a)` and a
+/// `[k, n]` `op(b)`.
+pub fn gemm<T: Num>(
+    alpha: T,
+    a: &Tensor<T>,
+    trans_a: bool,
+    b: &Tensor<T>,
+    trans_b: bool,
+    beta: T,
+    c: &mut Tensor<T>,
+) -> Result<()> {
+    let a = Operand::new(a, trans_a)?;
+    let b = Operand::new(b, trans_b)?;
+    ensure_len(a.cols, b.rows)?;
+    ensure_shape(c, &[a.rows, b.cols])?;
+
+    scale(c, beta);
+    if alpha == T::zero() {
+        return Ok(());
+    }
+    let (rs, cs) = (c.strides[0], c.strides[1]);
+    for i in 0..a.rows {
+        for l in 0..a.cols {
+            let x = alpha * a.get(i, l);
+            let row = c.offset + i * rs;
+            for j in 0..b.cols {
+                let slot = &mut c.data[row + j * cs];
+                *slot += x * b.get(l, j);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Matrix-vector multiply: `y = alpha·op(a)·x + beta·y`.
+pub fn gemv<T: Num>(
+    alpha: T,
+    a: &Tensor<T>,
+    trans_a: bool,
+    x: &Tensor<T>,
+    beta: T,
+    y: &mut Tensor<T>,
+) -> Result<()> {
+    let a = Operand::new(a, trans_a)?;
+    ensure_rank(x, 1)?;
+    ensure_len(a.cols, x.shape[0])?;
+    ensure_shape(y, &[a.rows])?;
+
+    scale(y, beta);
+    if alpha == T::zero() {
+        return Ok(());
+    }
+    let x: Vec<T> = x.iter().copied().collect();
+    for i in 0..a.rows {
+        let sum = x
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |acc, (l, &v)| acc + a.get(i, l) * v);
+        let slot = &mut y.data[y.offset + i * y.strides[0]];
+        *slot += alpha * sum;
+    }
+    Ok(())
+}
+
+/// `y += alpha·x`, where `x` must broadcast to `y`'s shape.
+pub fn axpy<T: Num>(alpha: T, x: &Tensor<T>, y: &mut Tensor<T>) -> Result<()> {
+    y.zip_mut_with(x, |y, &x| *y += alpha * x)
+}
+
+/// Rank-1 update: `a += alpha·x·yᵀ` for vectors `x` of length `m` and `y` of
+/// length `n`, with `a` of shape `[m, n]`.
+pub fn ger<T: Num>(alpha: T, x: &Tensor<T>, y: &Tensor<T>, a: &mut Tensor<T>) -> Result<()> {
+    ensure_rank(x, 1)?;
+    ensure_rank(y, 1)?;
+    ensure_shape(a, &[x.shape[0], y.shape[0]])?;
+
+    let y: Vec<T> = y.iter().copied().collect();
+    let (rs, cs) = (a.strides[0], a.strides[1]);
+    for (i, &xi) in x.iter().enumerate() {
+        let row = a.offset + i * rs;
+        for (j, &yj) in y.iter().enumerate() {
+            let slot = &mut a.data[row + j * cs];
+            *slot += alpha * xi * yj;
+        }
+    }
+    Ok(())
+}
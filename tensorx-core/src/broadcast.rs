@@ -0,0 +1,73 @@
+use crate::error::{Result, TensorError};
+use crate::tensor::{Positions, Tensor};
+use crate::view::TensorView;
+
+/// Computes the shape two operands broadcast to, NumPy style: shapes are
+/// aligned from the trailing axis and each pair must match or contain a 1.
+pub fn broadcast_shapes(lhs: &[usize], rhs: &[usize]) -> Result<Vec<usize>> {
+    let rank = lhs.len().max(rhs.len());
+    let mut shape = vec![0; rank];
+
+    for (axis, out) in shape.iter_mut().enumerate() {
+        let l = (axis + lhs.len()).checked_sub(rank).map_or(1, |i| lhs[i]);
+        let r = (axis + rhs.len()).checked_sub(rank).map_or(1, |i| rhs[i]);
+        *out = match (l, r) {
+            _ if l == r => l,
+            (1, _) => r,
+            (_, 1) => l,
+            _ => {
+                return Err(TensorError::BroadcastError {
+                    lhs: lhs.to_vec(),
+                    rhs: rhs.to_vec(),
+                });
+            }
+        };
+    }
+
+    Ok(shape)
+}
+
+/// Strides that read a layout of `shape`/`strides` as if it had shape `target`,
+/// using a stride of zero along broadcast axes.
+pub(crate) fn broadcast_strides(
+    shape: &[usize],
+    strides: &[usize],
+    target: &[usize],
+) -> Result<Vec<usize>> {
+    let error = || TensorError::BroadcastError {
+        lhs: shape.to_vec(),
+        rhs: target.to_vec(),
+    };
+
+    if shape.len() > target.len() {
+        return Err(error());
+    }
+
+    let lead = target.len() - shape.len();
+    let mut out = vec![0; target.len()];
+    for (axis, (&len, &stride)) in shape.iter().zip(strides).enumerate() {
+        match len {
+            _ if len == target[lead + axis] => out[lead + axis] = stride,
+            1 => out[lead + axis] = 0,
+            _ => return Err(error()),
+        }
+    }
+    Ok(out)
+}
+
+impl<T> Tensor<T> {
+    /// Returns a read-only view of the tensor broadcast to `shape`.
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<TensorView<'_, T>> {
+        Ok(TensorView {
+            data: &self.data,
+            shape: shape.to_vec(),
+            strides: broadcast_strides(&self.shape, &self.strides, shape)?,
+            offset: self.offset,
+        })
+    }
+
+    pub(crate) fn broadcast_positions(&self, shape: &[usize]) -> Result<Positions> {
+        let strides = broadcast_strides(&self.shape, &self.strides, shape)?;
+        Ok(Positions::new(shape, &strides, self.offset))
+    }
+}
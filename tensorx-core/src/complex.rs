@@ -0,0 +1,217 @@
+//! Complex numbers as tensor elements.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+use crate::element::{Element, Float, Num};
+use crate::error::Result;
+use crate::tensor::Tensor;
+
+/// A complex number `re + im·i` over a floating point type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+pub type Complex32 = Complex<f32>;
+pub type Complex64 = Complex<f64>;
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Float> Complex<T> {
+    /// `e^(iθ)`, computed in `f64` so that twiddle factors stay accurate for
+    /// `f32` too.
+    pub fn cis(theta: f64) -> Self {
+        Self::new(T::from_f64(theta.cos()), T::from_f64(theta.sin()))
+    }
+
+    /// `r·e^(iθ)`.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Magnitude `|z|`.
+    pub fn abs(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// Phase angle in `(-π, π]`.
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Principal natural logarithm.
+    pub fn ln(self) -> Self {
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    /// Principal square root, with a non-negative real part.
+    pub fn sqrt(self) -> Self {
+        let two = T::one() + T::one();
+        let r = self.abs();
+        let re = ((r + self.re) / two).sqrt();
+        let im = ((r - self.re) / two).sqrt();
+        Self::new(re, if self.im < T::zero() { -im } else { im })
+    }
+
+    /// Squared magnitude, `re² + im²`.
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn scale(self, k: T) -> Self {
+        Self::new(self.re * k, self.im * k)
+    }
+}
+
+impl<T: Float> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Float> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Float> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Float> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let d = rhs.norm_sqr();
+        let n = self * rhs.conj();
+        Self::new(n.re / d, n.im / d)
+    }
+}
+
+impl<T: Float> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+macro_rules! impl_complex_assign {
+    ($($OpAssign:ident, $op_assign:ident, $op:tt);*) => {
+        $(
+            impl<T: Float> $OpAssign for Complex<T> {
+                fn $op_assign(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_complex_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /
+);
+
+impl<T: Float> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+}
+
+impl<T: Float> Element for Complex<T> {}
+
+impl<T: Float> Num for Complex<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::new(T::from_f64(value), T::zero())
+    }
+
+    /// The real part; the imaginary part is discarded.
+    fn to_f64(self) -> f64 {
+        self.re.to_f64()
+    }
+
+    fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+}
+
+impl<T: Float> Tensor<Complex<T>> {
+    /// Builds a complex tensor from broadcastable real and imaginary parts.
+    pub fn from_parts(re: &Tensor<T>, im: &Tensor<T>) -> Result<Self> {
+        re.zip_with(im, |&re, &im| Complex::new(re, im))
+    }
+
+    pub fn real(&self) -> Result<Tensor<T>> {
+        Ok(self.map(|z| z.re))
+    }
+
+    pub fn imag(&self) -> Result<Tensor<T>> {
+        Ok(self.map(|z| z.im))
+    }
+
+    /// Elementwise magnitude.
+    pub fn abs(&self) -> Result<Tensor<T>> {
+        Ok(self.map(|z| z.abs()))
+    }
+
+    /// Elementwise phase angle in `(-π, π]`.
+    pub fn angle(&self) -> Result<Tensor<T>> {
+        Ok(self.map(|z| z.arg()))
+    }
+
+    pub fn conj(&self) -> Result<Self> {
+        Ok(self.map(|z| z.conj()))
+    }
+
+    pub fn exp(&self) -> Result<Self> {
+        Ok(self.map(|z| z.exp()))
+    }
+
+    /// Elementwise principal logarithm.
+    pub fn log(&self) -> Result<Self> {
+        Ok(self.map(|z| z.ln()))
+    }
+
+    /// Elementwise principal square root.
+    pub fn sqrt(&self) -> Result<Self> {
+        Ok(self.map(|z| z.sqrt()))
+    }
+}
@@ -0,0 +1,66 @@
+//! Cumulative operations along an axis, built on [`Tensor::scan`].
+
+use crate::element::{Float, Num, Real};
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+impl<T: Num> Tensor<T> {
+    /// Running sum along `axis`.
+    pub fn cumsum(&self, axis: usize) -> Result<Self> {
+        self.scan(axis, |&a, &b| a + b)
+    }
+
+    /// Running product along `axis`.
+    pub fn cumprod(&self, axis: usize) -> Result<Self> {
+        self.scan(axis, |&a, &b| a * b)
+    }
+
+    /// The `n`-th discrete difference along `axis`, `out[i] = x[i + 1] - x[i]`
+    /// applied `n` times. The axis shrinks by `n`, down to length zero.
+    pub fn diff(&self, n: usize, axis: usize) -> Result<Self> {
+        let rank = self.shape.len();
+        let len = *self
+            .shape
+            .get(axis)
+            .ok_or(TensorError::InvalidAxis { axis, rank })?;
+        let out_len = len.saturating_sub(n);
+
+        self.map_lanes(axis, out_len, |lane| {
+            let mut values: Vec<T> = lane.iter().copied().collect();
+            for _ in 0..n.min(len) {
+                values = values.windows(2).map(|w| w[1] - w[0]).collect();
+            }
+            values
+        })
+    }
+}
+
+impl<T: Real> Tensor<T> {
+    /// Running maximum along `axis`. A NaN propagates to the rest of its lane.
+    pub fn cummax(&self, axis: usize) -> Result<Self> {
+        self.scan(axis, |&a, &b| a.maximum(b))
+    }
+
+    /// Running minimum along `axis`. A NaN propagates to the rest of its lane.
+    pub fn cummin(&self, axis: usize) -> Result<Self> {
+        self.scan(axis, |&a, &b| a.minimum(b))
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    /// Running `ln(Σ exp(x))` along `axis`, computed without overflow by
+    /// factoring out the larger operand at every step.
+    pub fn logcumsumexp(&self, axis: usize) -> Result<Self> {
+        self.scan(axis, |&a, &b| {
+            if a.is_nan() || b.is_nan() {
+                return if a.is_nan() { a } else { b };
+            }
+            let (hi, lo) = if a >= b { (a, b) } else { (b, a) };
+            // Also avoids `inf - inf` when both operands are infinite.
+            if lo == T::neg_infinity() || hi == T::infinity() {
+                return hi;
+            }
+            hi + ((lo - hi).exp() + T::one()).ln()
+        })
+    }
+}
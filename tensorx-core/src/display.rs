@@ -0,0 +1,271 @@
+use std::cell::Cell;
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::complex::Complex;
+use crate::element::Float;
+use crate::tensor::Tensor;
+use crate::view::TensorView;
+
+/// Controls how tensors are rendered by their `Display` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Digits after the decimal point for floating point elements.
+    pub precision: usize,
+    /// Tensors with more elements than this are summarized with `...`.
+    pub threshold: usize,
+    /// Number of leading and trailing entries kept per axis when summarizing.
+    pub edge_items: usize,
+    /// Rows are wrapped once they would exceed this many characters.
+    pub line_width: usize,
+}
+
+impl PrintOptions {
+    pub const DEFAULT: PrintOptions = PrintOptions {
+        precision: 4,
+        threshold: 1000,
+        edge_items: 3,
+        line_width: 75,
+    };
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static GLOBAL_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions::DEFAULT);
+
+thread_local! {
+    static SCOPED_OPTIONS: Cell<Option<PrintOptions>> = const { Cell::new(None) };
+}
+
+/// Returns the options currently in effect on this thread.
+pub fn print_options() -> PrintOptions {
+    SCOPED_OPTIONS
+        .with(Cell::get)
+        .unwrap_or_else(|| *GLOBAL_OPTIONS.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Replaces the process-wide print options.
+pub fn set_print_options(options: PrintOptions) {
+    *GLOBAL_OPTIONS.write().unwrap_or_else(|e| e.into_inner()) = options;
+}
+
+/// Runs `f` with `options` in effect on the current thread only, restoring the
+/// previous options afterwards (even if `f` panics).
+pub fn with_print_options<R>(options: PrintOptions, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<PrintOptions>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED_OPTIONS.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPED_OPTIONS.with(|cell| cell.replace(Some(options))));
+    f()
+}
+
+/// Element types that know how to render themselves inside a tensor.
+pub trait DisplayElement {
+    fn format_element(&self, options: &PrintOptions) -> String;
+}
+
+macro_rules! impl_display_plain {
+    ($($t:ty),*) => {
+        $(impl DisplayElement for $t {
+            fn format_element(&self, _options: &PrintOptions) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+macro_rules! impl_display_float {
+    ($($t:ty),*) => {
+        $(impl DisplayElement for $t {
+            fn format_element(&self, options: &PrintOptions) -> String {
+                format!("{:.*}", options.precision, self)
+            }
+        })*
+    };
+}
+
+impl_display_plain!(
+    bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_display_float!(f32, f64);
+
+/// Rendered as `re+imj`, like numpy.
+impl<T: Float + DisplayElement> DisplayElement for Complex<T> {
+    fn format_element(&self, options: &PrintOptions) -> String {
+        let sign = if self.im < T::zero() { '-' } else { '+' };
+        format!(
+            "{}{sign}{}j",
+            self.re.format_element(options),
+            self.im.abs().format_element(options)
+        )
+    }
+}
+
+struct Renderer<'a, T> {
+    data: &'a [T],
+    shape: &'a [usize],
+    strides: &'a [usize],
+    offset: usize,
+    options: PrintOptions,
+    summarize: bool,
+}
+
+impl<T: DisplayElement> Renderer<'_, T> {
+    /// Positions shown along `axis`; `None` marks the elided middle.
+    fn visible(&self, axis: usize) -> Vec<Option<usize>> {
+        let len = self.shape[axis];
+        let edge = self.options.edge_items;
+        if self.summarize && len > 2 * edge {
+            (0..edge)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((len - edge..len).map(Some))
+                .collect()
+        } else {
+            (0..len).map(Some).collect()
+        }
+    }
+
+    /// The element at `index`, or `fmt::Error` if the layout points past
+    /// the data.
+    fn element(&self, index: &[usize]) -> Result<&T, fmt::Error> {
+        let flat = index
+            .iter()
+            .zip(self.strides)
+            .try_fold(self.offset, |acc, (i, s)| {
+                acc.checked_add(i.checked_mul(*s)?)
+            });
+        flat.and_then(|flat| self.data.get(flat)).ok_or(fmt::Error)
+    }
+
+    fn collect_cells(&self, index: &mut Vec<usize>, cells: &mut Vec<String>) -> fmt::Result {
+        if index.len() == self.shape.len() {
+            cells.push(self.element(index)?.format_element(&self.options));
+            return Ok(());
+        }
+        for i in self.visible(index.len()).into_iter().flatten() {
+            index.push(i);
+            self.collect_cells(index, cells)?;
+            index.pop();
+        }
+        Ok(())
+    }
+
+    fn render(
+        &self,
+        out: &mut String,
+        index: &mut Vec<usize>,
+        cells: &mut impl Iterator<Item = String>,
+        width: usize,
+    ) {
+        let axis = index.len();
+        let rank = self.shape.len();
+        let indent = axis + 1;
+        out.push('[');
+
+        if axis + 1 == rank {
+            let mut line_len = indent;
+            for (k, pos) in self.visible(axis).into_iter().enumerate() {
+                let cell = match pos {
+                    Some(_) => format!("{:>width$}", cells.next().unwrap_or_default()),
+                    None => format!("{:>width$}", "..."),
+                };
+                if k > 0 {
+                    if line_len + 1 + cell.len() > self.options.line_width {
+                        out.push('\n');
+                        out.push_str(&" ".repeat(indent));
+                        line_len = indent;
+                    } else {
+                        out.push(' ');
+                        line_len += 1;
+                    }
+                }
+                out.push_str(&cell);
+                line_len += cell.len();
+            }
+        } else {
+            for (k, pos) in self.visible(axis).into_iter().enumerate() {
+                if k > 0 {
+                    out.push_str(&"\n".repeat(rank - axis - 1));
+                    out.push_str(&" ".repeat(indent));
+                }
+                match pos {
+                    Some(i) => {
+                        index.push(i);
+                        self.render(out, index, cells, width);
+                        index.pop();
+                    }
+                    None => out.push_str("..."),
+                }
+            }
+        }
+
+        out.push(']');
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shape.is_empty() {
+            return f.write_str(&self.element(&[])?.format_element(&self.options));
+        }
+        if self.shape.contains(&0) {
+            return f.write_str("[]");
+        }
+
+        let mut cells = Vec::new();
+        self.collect_cells(&mut Vec::with_capacity(self.shape.len()), &mut cells)?;
+        let width = cells.iter().map(String::len).max().unwrap_or(0);
+
+        let mut out = String::new();
+        self.render(
+            &mut out,
+            &mut Vec::with_capacity(self.shape.len()),
+            &mut cells.into_iter(),
+            width,
+        );
+        f.write_str(&out)
+    }
+}
+
+fn display_strided<T: DisplayElement>(
+    f: &mut fmt::Formatter<'_>,
+    data: &[T],
+    shape: &[usize],
+    strides: &[usize],
+    offset: usize,
+) -> fmt::Result {
+    let mut options = print_options();
+    if let Some(precision) = f.precision() {
+        options.precision = precision;
+    }
+
+    Renderer {
+        data,
+        shape,
+        strides,
+        offset,
+        options,
+        summarize: shape.iter().product::<usize>() > options.threshold,
+    }
+    .write(f)
+}
+
+impl<T: DisplayElement> fmt::Display for Tensor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_strided(f, &self.data, &self.shape, &self.strides, self.offset)
+    }
+}
+
+impl<T: DisplayElement> fmt::Display for TensorView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_strided(f, self.data, &self.shape, &self.strides, self.offset)
+    }
+}
@@ -0,0 +1,359 @@
+//! Einstein summation over any number of operands.
+//!
+//! Subscripts follow numpy: `"bij,bjk->bik"`, with implicit output when `->`
+//! is omitted, repeated labels within one operand selecting its diagonal,
+//! and `...` standing for broadcast leading axes.
+
+use std::collections::HashMap;
+
+use crate::element::Num;
+use crate::error::{Result, TensorError};
+use crate::tensor::{Tensor, matmul_kernel};
+
+/// An axis label: a letter, or one of the broadcast axes covered by `...`,
+/// numbered left to right. Ellipsis axes sort first, matching where numpy
+/// places them in implicit output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Label {
+    Ellipsis(usize),
+    Letter(char),
+}
+
+enum Token {
+    Letter(char),
+    Ellipsis,
+}
+
+struct Operand<T> {
+    labels: Vec<Label>,
+    tensor: Tensor<T>,
+}
+
+fn invalid(detail: impl Into<String>) -> TensorError {
+    TensorError::InvalidSubscripts(detail.into())
+}
+
+fn tokenize(term: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = term;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("...") {
+            if tokens.iter().any(|t| matches!(t, Token::Ellipsis)) {
+                return Err(invalid(format!("more than one ellipsis in {term:?}")));
+            }
+            tokens.push(Token::Ellipsis);
+            rest = after;
+        } else if c.is_ascii_alphabetic() {
+            tokens.push(Token::Letter(c));
+            rest = &rest[1..];
+        } else {
+            return Err(invalid(format!("unexpected {c:?} in {term:?}")));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Expands tokens into labels; an ellipsis covering `ellipsis_dims` axes is
+/// right-aligned within the `total` broadcast axes.
+fn expand(tokens: &[Token], ellipsis_dims: usize, total: usize) -> Vec<Label> {
+    tokens
+        .iter()
+        .flat_map(|token| match *token {
+            Token::Letter(c) => vec![Label::Letter(c)],
+            Token::Ellipsis => (total - ellipsis_dims..total)
+                .map(Label::Ellipsis)
+                .collect(),
+        })
+        .collect()
+}
+
+/// Parses `subscripts` against the operands' ranks, returning each operand's
+/// labels and the output labels.
+fn parse<T>(subscripts: &str, operands: &[&Tensor<T>]) -> Result<(Vec<Vec<Label>>, Vec<Label>)> {
+    let subscripts: String = subscripts.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match subscripts.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (subscripts.as_str(), None),
+    };
+
+    let terms = inputs
+        .split(',')
+        .map(tokenize)
+        .collect::<Result<Vec<_>>>()?;
+    if terms.len() != operands.len() {
+        return Err(invalid(format!(
+            "{} input terms for {} operands",
+            terms.len(),
+            operands.len()
+        )));
+    }
+
+    let mut ellipsis_dims = Vec::with_capacity(terms.len());
+    for (tokens, operand) in terms.iter().zip(operands) {
+        let letters = tokens
+            .iter()
+            .filter(|t| matches!(t, Token::Letter(_)))
+            .count();
+        let rank = operand.shape.len();
+        let has_ellipsis = letters < tokens.len();
+        if rank < letters || (!has_ellipsis && rank != letters) {
+            return Err(TensorError::RankMismatch {
+                expected: letters,
+                actual: rank,
+            });
+        }
+        ellipsis_dims.push(rank - letters);
+    }
+    let total = ellipsis_dims.iter().copied().max().unwrap_or(0);
+
+    let inputs: Vec<Vec<Label>> = terms
+        .iter()
+        .zip(&ellipsis_dims)
+        .map(|(tokens, &dims)| expand(tokens, dims, total))
+        .collect();
+
+    let output = match output {
+        Some(output) => {
+            let labels = expand(&tokenize(output)?, total, total);
+            for (i, label) in labels.iter().enumerate() {
+                if labels[..i].contains(label) {
+                    return Err(invalid(format!("repeated output label in {output:?}")));
+                }
+                if !inputs.iter().any(|l| l.contains(label)) {
+                    return Err(invalid(format!("output label {label:?} not in any input")));
+                }
+            }
+            labels
+        }
+        None => {
+            let mut counts: HashMap<Label, usize> = HashMap::new();
+            for label in inputs.iter().flatten() {
+                *counts.entry(*label).or_default() += 1;
+            }
+            let mut labels: Vec<Label> = counts
+                .into_iter()
+                .filter(|&(label, n)| n == 1 || matches!(label, Label::Ellipsis(_)))
+                .map(|(label, _)| label)
+                .collect();
+            labels.sort();
+            labels
+        }
+    };
+    Ok((inputs, output))
+}
+
+/// Resolves the size of every label. Ellipsis axes broadcast; letters must
+/// agree exactly.
+fn label_sizes<T>(inputs: &[Vec<Label>], operands: &[&Tensor<T>]) -> Result<HashMap<Label, usize>> {
+    let mut sizes = HashMap::new();
+    for (labels, operand) in inputs.iter().zip(operands) {
+        for (&label, &dim) in labels.iter().zip(&operand.shape) {
+            let size = sizes.entry(label).or_insert(dim);
+            if *size == dim || (matches!(label, Label::Ellipsis(_)) && dim == 1) {
+                continue;
+            }
+            if matches!(label, Label::Ellipsis(_)) && *size == 1 {
+                *size = dim;
+                continue;
+            }
+            return Err(TensorError::DimensionalMismatch {
+                expected: *size,
+                actual: dim,
+            });
+        }
+    }
+    Ok(sizes)
+}
+
+impl<T: Num> Operand<T> {
+    /// Collapses repeated labels onto their diagonal by summing the strides.
+    fn new(tensor: &Tensor<T>, labels: &[Label]) -> Self {
+        let mut unique: Vec<Label> = Vec::new();
+        let mut shape = Vec::new();
+        let mut strides: Vec<usize> = Vec::new();
+        for (i, label) in labels.iter().enumerate() {
+            match unique.iter().position(|l| l == label) {
+                Some(j) => strides[j] += tensor.strides[i],
+                None => {
+                    unique.push(*label);
+                    shape.push(tensor.shape[i]);
+                    strides.push(tensor.strides[i]);
+                }
+            }
+        }
+        Self {
+            labels: unique,
+            tensor: Tensor {
+                data: tensor.data.clone(),
+                shape,
+                strides,
+                offset: tensor.offset,
+            },
+        }
+    }
+
+    fn sum_out(mut self, label: Label) -> Result<Self> {
+        if let Some(axis) = self.labels.iter().position(|&l| l == label) {
+            self.tensor = self.tensor.fold_axis(axis, T::zero(), |acc, &x| acc + x)?;
+            self.labels.remove(axis);
+        }
+        Ok(self)
+    }
+
+    /// Sums out every label not in `keep`.
+    fn reduce_to(mut self, keep: &[Label]) -> Result<Self> {
+        let extra: Vec<Label> = self
+            .labels
+            .iter()
+            .copied()
+            .filter(|l| !keep.contains(l))
+            .collect();
+        for label in extra {
+            self = self.sum_out(label)?;
+        }
+        Ok(self)
+    }
+
+    /// Data laid out contiguously in `order`, broadcast to the full sizes.
+    fn arrange(&self, order: &[Label], sizes: &HashMap<Label, usize>) -> Result<Vec<T>> {
+        let perm: Vec<usize> = order
+            .iter()
+            .map(|l| self.labels.iter().position(|m| m == l).unwrap_or(0))
+            .collect();
+        let shape: Vec<usize> = order.iter().map(|l| sizes[l]).collect();
+        let permuted = self.tensor.permute(&perm)?;
+        Ok(permuted.broadcast_to(&shape)?.iter().copied().collect())
+    }
+}
+
+fn volume(labels: &[Label], sizes: &HashMap<Label, usize>) -> usize {
+    labels.iter().map(|l| sizes[l]).product()
+}
+
+/// Contracts two operands as a batched matrix product, keeping `keep`.
+fn contract<T: Num>(
+    a: Operand<T>,
+    b: Operand<T>,
+    keep: &[Label],
+    sizes: &HashMap<Label, usize>,
+) -> Result<Operand<T>> {
+    let a_keep: Vec<Label> = keep.iter().chain(&b.labels).copied().collect();
+    let b_keep: Vec<Label> = keep.iter().chain(&a.labels).copied().collect();
+    let a = a.reduce_to(&a_keep)?;
+    let b = b.reduce_to(&b_keep)?;
+
+    let shared = |l: &&Label| b.labels.contains(l);
+    let batch: Vec<Label> = a
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|l| keep.contains(l))
+        .copied()
+        .collect();
+    let summed: Vec<Label> = a
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|l| !keep.contains(l))
+        .copied()
+        .collect();
+    let left: Vec<Label> = a
+        .labels
+        .iter()
+        .filter(|l| !b.labels.contains(l))
+        .copied()
+        .collect();
+    let right: Vec<Label> = b
+        .labels
+        .iter()
+        .filter(|l| !a.labels.contains(l))
+        .copied()
+        .collect();
+
+    let lhs = a.arrange(&[&batch[..], &left, &summed].concat(), sizes)?;
+    let rhs = b.arrange(&[&batch[..], &summed, &right].concat(), sizes)?;
+    let data = matmul_kernel(
+        volume(&batch, sizes),
+        volume(&left, sizes),
+        volume(&summed, sizes),
+        volume(&right, sizes),
+        &lhs,
+        &rhs,
+    );
+
+    let labels = [batch, left, right].concat();
+    let shape = labels.iter().map(|l| sizes[l]).collect();
+    Ok(Operand {
+        labels,
+        tensor: Tensor::from_aligned(shape, data)?,
+    })
+}
+
+/// Labels of every operand except `skip`, plus the output's.
+fn needed<T>(operands: &[Operand<T>], skip: &[usize], output: &[Label]) -> Vec<Label> {
+    operands
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !skip.contains(i))
+        .flat_map(|(_, op)| op.labels.iter().copied())
+        .chain(output.iter().copied())
+        .collect()
+}
+
+/// Evaluates an Einstein summation such as `einsum("bij,bjk->bik", &[&a, &b])`.
+///
+/// Operands are contracted pairwise in a greedy order that keeps each
+/// intermediate as small as possible, and every pairwise contraction runs as
+/// a batched matrix product. Labels missing from an explicit output,
+/// including ellipsis axes, are summed over.
+pub fn einsum<T: Num>(subscripts: &str, operands: &[&Tensor<T>]) -> Result<Tensor<T>> {
+    if operands.is_empty() {
+        return Err(invalid("at least one operand is required"));
+    }
+    let (inputs, output) = parse(subscripts, operands)?;
+    let sizes = label_sizes(&inputs, operands)?;
+
+    let mut ops: Vec<Operand<T>> = operands
+        .iter()
+        .zip(&inputs)
+        .map(|(t, labels)| Operand::new(t, labels))
+        .collect();
+
+    // Labels private to one operand can be summed before any contraction.
+    for i in 0..ops.len() {
+        let keep = needed(&ops, &[i], &output);
+        let op = ops.remove(i);
+        ops.insert(i, op.reduce_to(&keep)?);
+    }
+
+    while ops.len() > 1 {
+        let mut best = (0, 1, isize::MAX);
+        for i in 0..ops.len() {
+            for j in i + 1..ops.len() {
+                let keep = needed(&ops, &[i, j], &output);
+                let mut result: Vec<Label> = ops[i].labels.clone();
+                result.extend(ops[j].labels.iter().filter(|l| !ops[i].labels.contains(l)));
+                result.retain(|l| keep.contains(l));
+                let cost = volume(&result, &sizes) as isize
+                    - volume(&ops[i].labels, &sizes) as isize
+                    - volume(&ops[j].labels, &sizes) as isize;
+                if cost < best.2 {
+                    best = (i, j, cost);
+                }
+            }
+        }
+
+        let (i, j, _) = best;
+        let keep = needed(&ops, &[i, j], &output);
+        let b = ops.remove(j);
+        let a = ops.remove(i);
+        ops.push(contract(a, b, &keep, &sizes)?);
+    }
+
+    let last = ops.remove(0).reduce_to(&output)?;
+    let perm: Vec<usize> = output
+        .iter()
+        .map(|l| last.labels.iter().position(|m| m == l).unwrap_or(0))
+        .collect();
+    Ok(last.tensor.permute(&perm)?.contiguous())
+}
@@ -0,0 +1,363 @@
+//! Element type hierarchy.
+//!
+//! - [`Element`]: anything that can be stored in a tensor.
+//! - [`Num`]: elements with arithmetic, `zero`/`one` and lossy casting.
+//! - [`Real`]: ordered numbers, with NaN-aware `minimum`/`maximum`.
+//! - [`Float`] and [`Int`]: the floating point and integer primitives.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Scalar types that can be stored in a [`Tensor`](crate::tensor::Tensor)
+/// and used as the leaves of nested literals.
+pub trait Element: Copy + Default + PartialEq + Debug + 'static {}
+
+impl Element for bool {}
+
+/// Element types that support arithmetic.
+pub trait Num:
+    Element
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+
+    /// Converts with `as` semantics: integers truncate towards zero and
+    /// saturate at their bounds, NaN becomes 0.
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn from_usize(value: usize) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    /// Converts between element types by way of `f64`, so integers wider
+    /// than 53 bits may lose precision.
+    fn cast<U: Num>(self) -> U {
+        U::from_f64(self.to_f64())
+    }
+
+    fn is_nan(self) -> bool {
+        false
+    }
+}
+
+/// Numbers on the real line, i.e. ones that can be ordered.
+pub trait Real: Num + PartialOrd {
+    /// Smallest finite value (or negative infinity for floats).
+    fn min_value() -> Self;
+    /// Largest finite value (or infinity for floats).
+    fn max_value() -> Self;
+
+    /// The smaller of two values; NaN if either is NaN.
+    fn minimum(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            if self.is_nan() { self } else { other }
+        } else if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The larger of two values; NaN if either is NaN.
+    fn maximum(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            if self.is_nan() { self } else { other }
+        } else if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// Primitive integer types.
+pub trait Int: Real + Ord + Eq + Hash + Rem<Output = Self> {
+    const SIGNED: bool;
+    const BITS: u32;
+
+    /// The exact value as a `usize`, or `None` if it is negative or too large.
+    fn to_usize(self) -> Option<usize>;
+}
+
+/// Floating point element types, providing the math used by the activation
+/// and elementary function ops.
+pub trait Float: Real + Neg<Output = Self> {
+    fn nan() -> Self;
+    fn infinity() -> Self;
+    fn neg_infinity() -> Self;
+    fn epsilon() -> Self;
+
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+
+    fn abs(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    /// Four-quadrant arctangent of `self / other`.
+    fn atan2(self, other: Self) -> Self;
+    /// `sqrt(self² + other²)` without intermediate overflow.
+    fn hypot(self, other: Self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn atanh(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    /// Rounds half-way cases away from zero.
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    /// `ln(1 + self)`, accurate for small `self`.
+    fn ln_1p(self) -> Self;
+    /// `e^self - 1`, accurate for small `self`.
+    fn exp_m1(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($signed:expr; $($t:ty),*) => {
+        $(
+            impl Element for $t {}
+
+            impl Num for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+            }
+
+            impl Real for $t {
+                fn min_value() -> Self {
+                    <$t>::MIN
+                }
+
+                fn max_value() -> Self {
+                    <$t>::MAX
+                }
+            }
+
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+                const BITS: u32 = <$t>::BITS;
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(true; i8, i16, i32, i64, i128, isize);
+impl_int!(false; u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Element for $t {}
+
+            impl Num for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+            }
+
+            impl Real for $t {
+                fn min_value() -> Self {
+                    <$t>::NEG_INFINITY
+                }
+
+                fn max_value() -> Self {
+                    <$t>::INFINITY
+                }
+            }
+
+            impl Float for $t {
+                fn nan() -> Self {
+                    <$t>::NAN
+                }
+
+                fn infinity() -> Self {
+                    <$t>::INFINITY
+                }
+
+                fn neg_infinity() -> Self {
+                    <$t>::NEG_INFINITY
+                }
+
+                fn epsilon() -> Self {
+                    <$t>::EPSILON
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn is_infinite(self) -> bool {
+                    <$t>::is_infinite(self)
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn tanh(self) -> Self {
+                    <$t>::tanh(self)
+                }
+
+                fn powf(self, n: Self) -> Self {
+                    <$t>::powf(self, n)
+                }
+
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    <$t>::atan2(self, other)
+                }
+
+                fn hypot(self, other: Self) -> Self {
+                    <$t>::hypot(self, other)
+                }
+
+                fn tan(self) -> Self {
+                    <$t>::tan(self)
+                }
+
+                fn asin(self) -> Self {
+                    <$t>::asin(self)
+                }
+
+                fn acos(self) -> Self {
+                    <$t>::acos(self)
+                }
+
+                fn atan(self) -> Self {
+                    <$t>::atan(self)
+                }
+
+                fn sinh(self) -> Self {
+                    <$t>::sinh(self)
+                }
+
+                fn cosh(self) -> Self {
+                    <$t>::cosh(self)
+                }
+
+                fn asinh(self) -> Self {
+                    <$t>::asinh(self)
+                }
+
+                fn acosh(self) -> Self {
+                    <$t>::acosh(self)
+                }
+
+                fn atanh(self) -> Self {
+                    <$t>::atanh(self)
+                }
+
+                fn floor(self) -> Self {
+                    <$t>::floor(self)
+                }
+
+                fn ceil(self) -> Self {
+                    <$t>::ceil(self)
+                }
+
+                fn round(self) -> Self {
+                    <$t>::round(self)
+                }
+
+                fn trunc(self) -> Self {
+                    <$t>::trunc(self)
+                }
+
+                fn ln_1p(self) -> Self {
+                    <$t>::ln_1p(self)
+                }
+
+                fn exp_m1(self) -> Self {
+                    <$t>::exp_m1(self)
+                }
+
+                fn log2(self) -> Self {
+                    <$t>::log2(self)
+                }
+
+                fn log10(self) -> Self {
+                    <$t>::log10(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -4,33 +4,97 @@ pub type Result<T> = std::result::Result<T, TensorError>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TensorError {
-    ShapeMismatch,
-    IndexOutOfBounds,
+    ShapeMismatch {
+        expected: Vec<usize>,
+        actual: Vec<usize>,
+    },
+    IndexOutOfBounds {
+        index: Vec<usize>,
+        shape: Vec<usize>,
+    },
     NotImplemented,
-    DimensionalMismatch,
-    InvalidPermutation,
-    BroadcastError,
+    DimensionalMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    RankMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    InvalidPermutation {
+        dims: Vec<usize>,
+    },
+    BroadcastError {
+        lhs: Vec<usize>,
+        rhs: Vec<usize>,
+    },
     MixedStorage,
     IncompatibleTypes(&'static str),
-    SerializationError,
-    DeserializationError,
-    InvalidAxis,
+    SerializationError(String),
+    DeserializationError(String),
+    InvalidAxis {
+        axis: usize,
+        rank: usize,
+    },
+    GpuError(String),
+    Singular,
+    NotPositiveDefinite,
+    InvalidSubscripts(String),
+    InvalidArgument(String),
 }
 
 impl fmt::Display for TensorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            TensorError::ShapeMismatch => write!(f, "Tensor shapes do not match"),
-            TensorError::IndexOutOfBounds => write!(f, "Index is out of bounds"),
+            TensorError::ShapeMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Tensor shapes do not match: expected {expected:?}, got {actual:?}"
+                )
+            }
+            TensorError::IndexOutOfBounds { index, shape } => {
+                write!(f, "Index {index:?} is out of bounds for shape {shape:?}")
+            }
             TensorError::NotImplemented => write!(f, "T not implemented"),
-            TensorError::InvalidPermutation => write!(f, "Invalid permutation"),
-            TensorError::DimensionalMismatch => write!(f, "Dimensions do not match"),
-            TensorError::MixedStorage => write!(f, ""),
-            TensorError::IncompatibleTypes(..) => write!(f, ""),
-            TensorError::BroadcastError => write!(f, ""),
-            TensorError::DeserializationError => write!(f, "Deserialization error"),
-            TensorError::SerializationError => write!(f, "Serialization error"),
-            TensorError::InvalidAxis => write!(f, "Invalid axis"),
+            TensorError::InvalidPermutation { dims } => {
+                write!(f, "Invalid permutation {dims:?}")
+            }
+            TensorError::DimensionalMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Dimensions do not match: expected {expected}, got {actual}"
+                )
+            }
+            TensorError::RankMismatch { expected, actual } => {
+                write!(f, "Expected a tensor of rank {expected}, got rank {actual}")
+            }
+            TensorError::MixedStorage => {
+                write!(f, "Operands live in different storage (e.g. CPU and GPU)")
+            }
+            TensorError::IncompatibleTypes(detail) => {
+                write!(f, "Incompatible element types: {detail}")
+            }
+            TensorError::BroadcastError { lhs, rhs } => {
+                write!(f, "Shapes {lhs:?} and {rhs:?} cannot be broadcast together")
+            }
+            TensorError::DeserializationError(detail) => {
+                write!(f, "Deserialization error: {detail}")
+            }
+            TensorError::SerializationError(detail) => {
+                write!(f, "Serialization error: {detail}")
+            }
+            TensorError::InvalidAxis { axis, rank } => {
+                write!(f, "Invalid axis {axis} for tensor of rank {rank}")
+            }
+            TensorError::GpuError(detail) => write!(f, "GPU error: {detail}"),
+            TensorError::Singular => write!(f, "Matrix is singular"),
+            TensorError::NotPositiveDefinite => {
+                write!(f, "Matrix is not positive definite")
+            }
+            TensorError::InvalidSubscripts(detail) => {
+                write!(f, "Invalid einsum subscripts: {detail}")
+            }
+            TensorError::InvalidArgument(detail) => write!(f, "Invalid argument: {detail}"),
         }
     }
 }
@@ -0,0 +1,350 @@
+//! Discrete Fourier transforms along tensor axes.
+//!
+//! Power-of-two lengths use an iterative radix-2 Cooley-Tukey transform;
+//! other lengths are reduced to a power-of-two convolution with Bluestein's
+//! algorithm, so every length runs in `O(n log n)`.
+
+use std::f64::consts::PI;
+
+use crate::complex::Complex;
+use crate::element::{Float, Num};
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+
+/// Where the `1/n` scaling of a transform pair is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FftNorm {
+    /// Forward transforms are unscaled, inverse ones scale by `1/n`.
+    #[default]
+    Backward,
+    /// Both directions scale by `1/√n`, making the transform unitary.
+    Ortho,
+    /// Forward transforms scale by `1/n`, inverse ones are unscaled.
+    Forward,
+}
+
+impl FftNorm {
+    fn factor<T: Float>(self, n: usize, inverse: bool) -> T {
+        let n = T::from_usize(n);
+        match (self, inverse) {
+            (FftNorm::Ortho, _) => T::one() / n.sqrt(),
+            (FftNorm::Backward, true) | (FftNorm::Forward, false) => T::one() / n,
+            _ => T::one(),
+        }
+    }
+}
+
+/// Unnormalised in-place transform; `inverse` flips the exponent's sign.
+fn transform<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(x, inverse);
+    } else {
+        bluestein(x, inverse);
+    }
+}
+
+fn radix2<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+
+    let twiddles: Vec<Complex<T>> = (0..n / 2)
+        .map(|k| Complex::cis(sign * 2.0 * PI * k as f64 / n as f64))
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let step = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let a = x[start + k];
+                let b = x[start + k + len / 2] * twiddles[k * step];
+                x[start + k] = a + b;
+                x[start + k + len / 2] = a - b;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Bluestein's chirp-z algorithm: rewrites `jk = (j² + k² - (k - j)²) / 2`
+/// to turn the DFT into a convolution evaluated with radix-2 transforms.
+fn bluestein<T: Float>(x: &mut [Complex<T>], inverse: bool) {
+    let n = x.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+    // k² is reduced modulo 2n to keep the chirp's phase accurate.
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|k| Complex::cis(sign * PI * ((k * k) % (2 * n)) as f64 / n as f64))
+        .collect();
+
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::zero(); m];
+    let mut b = vec![Complex::zero(); m];
+    for k in 0..n {
+        a[k] = x[k] * chirp[k];
+        b[k] = chirp[k].conj();
+        if k > 0 {
+            b[m - k] = chirp[k].conj();
+        }
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a *= *b;
+    }
+    radix2(&mut a, true);
+
+    let scale = T::one() / T::from_usize(m);
+    for k in 0..n {
+        x[k] = (a[k] * chirp[k]).scale(scale);
+    }
+}
+
+/// Transforms every lane along `axis`, cropping or zero-padding it to `n`.
+fn complex_along<T: Float>(
+    a: &Tensor<Complex<T>>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+    inverse: bool,
+) -> Result<Tensor<Complex<T>>> {
+    let rank = a.shape.len();
+    if axis >= rank {
+        return Err(TensorError::InvalidAxis { axis, rank });
+    }
+    let n = n.unwrap_or(a.shape[axis]);
+    let scale = norm.factor(n, inverse);
+    a.map_lanes(axis, n, |lane| {
+        let mut buf: Vec<Complex<T>> = lane.iter().copied().take(n).collect();
+        buf.resize(n, Complex::zero());
+        transform(&mut buf, inverse);
+        buf.into_iter().map(|z| z.scale(scale)).collect()
+    })
+}
+
+/// One-dimensional DFT along `axis`. The input is cropped or zero-padded to
+/// `n` points if given.
+pub fn fft<T: Float>(
+    a: &Tensor<Complex<T>>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+) -> Result<Tensor<Complex<T>>> {
+    complex_along(a, n, axis, norm, false)
+}
+
+/// Inverse of [`fft`].
+pub fn ifft<T: Float>(
+    a: &Tensor<Complex<T>>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+) -> Result<Tensor<Complex<T>>> {
+    complex_along(a, n, axis, norm, true)
+}
+
+/// Real transforms always have at least one frequency, so they need at
+/// least one point.
+fn check_points(n: usize) -> Result<()> {
+    if n == 0 {
+        return Err(TensorError::InvalidArgument(
+            "a real FFT needs at least one point".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// DFT of real input along `axis`, keeping only the `n / 2 + 1`
+/// non-negative frequencies; the rest follow from Hermitian symmetry.
+pub fn rfft<T: Float>(
+    a: &Tensor<T>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+) -> Result<Tensor<Complex<T>>> {
+    let rank = a.shape.len();
+    if axis >= rank {
+        return Err(TensorError::InvalidAxis { axis, rank });
+    }
+    let n = n.unwrap_or(a.shape[axis]);
+    check_points(n)?;
+    let scale = norm.factor(n, false);
+    a.map_lanes(axis, n / 2 + 1, |lane| {
+        let mut buf: Vec<Complex<T>> = lane.iter().take(n).map(|&x| Complex::from(x)).collect();
+        buf.resize(n, Complex::zero());
+        transform(&mut buf, false);
+        buf.into_iter()
+            .take(n / 2 + 1)
+            .map(|z| z.scale(scale))
+            .collect()
+    })
+}
+
+/// Inverse of [`rfft`], producing `n` real points along `axis`; `n` defaults
+/// to `2 * (m - 1)` for `m` input frequencies. The imaginary parts of the
+/// zero and Nyquist frequencies are ignored.
+pub fn irfft<T: Float>(
+    a: &Tensor<Complex<T>>,
+    n: Option<usize>,
+    axis: usize,
+    norm: FftNorm,
+) -> Result<Tensor<T>> {
+    let rank = a.shape.len();
+    if axis >= rank {
+        return Err(TensorError::InvalidAxis { axis, rank });
+    }
+    let n = n.unwrap_or(2 * a.shape[axis].saturating_sub(1));
+    check_points(n)?;
+    let scale = norm.factor(n, true);
+    a.map_lanes(axis, n, |lane| {
+        let half: Vec<Complex<T>> = lane.iter().copied().take(n / 2 + 1).collect();
+        let mut buf = vec![Complex::zero(); n];
+        for (k, slot) in buf.iter_mut().enumerate() {
+            let mirrored = k.min(n - k);
+            if let Some(&z) = half.get(mirrored) {
+                *slot = if mirrored == k { z } else { z.conj() };
+            }
+        }
+        if let Some(first) = buf.first_mut() {
+            first.im = T::zero();
+        }
+        if n.is_multiple_of(2) {
+            buf[n / 2].im = T::zero();
+        }
+        transform(&mut buf, true);
+        buf.into_iter().map(|z| z.re * scale).collect()
+    })
+}
+
+fn along_axes<T: Float>(
+    a: &Tensor<Complex<T>>,
+    axes: Option<&[usize]>,
+    norm: FftNorm,
+    inverse: bool,
+) -> Result<Tensor<Complex<T>>> {
+    let all: Vec<usize> = (0..a.shape.len()).collect();
+    let mut out = a.clone();
+    for &axis in axes.unwrap_or(&all) {
+        out = complex_along(&out, None, axis, norm, inverse)?;
+    }
+    Ok(out)
+}
+
+fn last_two_axes<T>(a: &Tensor<T>) -> Result<[usize; 2]> {
+    let rank = a.shape.len();
+    if rank < 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: rank,
+        });
+    }
+    Ok([rank - 2, rank - 1])
+}
+
+/// N-dimensional DFT over `axes`, or over every axis if `None`.
+pub fn fftn<T: Float>(
+    a: &Tensor<Complex<T>>,
+    axes: Option<&[usize]>,
+    norm: FftNorm,
+) -> Result<Tensor<Complex<T>>> {
+    along_axes(a, axes, norm, false)
+}
+
+/// Inverse of [`fftn`].
+pub fn ifftn<T: Float>(
+    a: &Tensor<Complex<T>>,
+    axes: Option<&[usize]>,
+    norm: FftNorm,
+) -> Result<Tensor<Complex<T>>> {
+    along_axes(a, axes, norm, true)
+}
+
+/// Two-dimensional DFT over the last two axes.
+pub fn fft2<T: Float>(a: &Tensor<Complex<T>>, norm: FftNorm) -> Result<Tensor<Complex<T>>> {
+    along_axes(a, Some(&last_two_axes(a)?), norm, false)
+}
+
+/// Inverse of [`fft2`].
+pub fn ifft2<T: Float>(a: &Tensor<Complex<T>>, norm: FftNorm) -> Result<Tensor<Complex<T>>> {
+    along_axes(a, Some(&last_two_axes(a)?), norm, true)
+}
+
+fn vector<T>(data: AlignedVec<T>) -> Tensor<T> {
+    let shape = vec![data.len()];
+    Tensor {
+        data,
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+/// Sample frequencies of an `n`-point [`fft`] with sample spacing `d`:
+/// `[0, 1, ..., n/2 - 1, -n/2, ..., -1] / (d * n)` for even `n`.
+pub fn fftfreq<T: Float>(n: usize, d: T) -> Tensor<T> {
+    let denom = d * T::from_usize(n);
+    vector(
+        (0..n)
+            .map(|k| {
+                let k = if k < n.div_ceil(2) {
+                    T::from_usize(k)
+                } else {
+                    -T::from_usize(n - k)
+                };
+                k / denom
+            })
+            .collect(),
+    )
+}
+
+/// Sample frequencies of an `n`-point [`rfft`]: `[0, 1, ..., n/2] / (d * n)`.
+pub fn rfftfreq<T: Float>(n: usize, d: T) -> Tensor<T> {
+    let denom = d * T::from_usize(n);
+    vector((0..n / 2 + 1).map(|k| T::from_usize(k) / denom).collect())
+}
+
+fn roll_axes<T: Clone>(
+    a: &Tensor<T>,
+    axes: Option<&[usize]>,
+    shift: impl Fn(usize) -> usize,
+) -> Result<Tensor<T>> {
+    let all: Vec<usize> = (0..a.shape.len()).collect();
+    let mut out = a.clone();
+    for &axis in axes.unwrap_or(&all) {
+        let rank = out.shape.len();
+        if axis >= rank {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+        let n = out.shape[axis];
+        let k = shift(n);
+        out = out.map_lanes(axis, n, |lane| {
+            let values: Vec<T> = lane.iter().cloned().collect();
+            (0..n).map(|i| values[(i + n - k) % n].clone()).collect()
+        })?;
+    }
+    Ok(out)
+}
+
+/// Moves the zero-frequency entry to the centre of each axis in `axes` (all
+/// axes if `None`).
+pub fn fftshift<T: Clone>(a: &Tensor<T>, axes: Option<&[usize]>) -> Result<Tensor<T>> {
+    roll_axes(a, axes, |n| n / 2)
+}
+
+/// Inverse of [`fftshift`]; differs from it only for odd lengths.
+pub fn ifftshift<T: Clone>(a: &Tensor<T>, axes: Option<&[usize]>) -> Result<Tensor<T>> {
+    roll_axes(a, axes, |n| n - n / 2)
+}
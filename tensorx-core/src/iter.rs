@@ -0,0 +1,383 @@
+//! Iterators that visit tensor elements in logical (row-major) order,
+//! regardless of the underlying strides.
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::error::{Result, TensorError};
+use crate::tensor::{Positions, Tensor};
+use crate::view::{TensorView, TensorViewMut};
+
+/// Shared references to the elements of a tensor in logical order.
+pub struct Iter<'a, T> {
+    data: &'a [T],
+    positions: Positions,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.positions.next().map(|i| &self.data[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutable references to the elements of a tensor in logical order.
+///
+/// Iteration stops early if the strides map two logical indices to the same
+/// element, since handing out two `&mut` to it would be unsound.
+pub struct IterMut<'a, T> {
+    data: NonNull<T>,
+    /// Length of the data; set to zero once iteration has stopped early.
+    len: usize,
+    positions: Positions,
+    /// Positions already handed out, tracked only for layouts that may
+    /// overlap.
+    seen: Option<Vec<bool>>,
+    _data: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: `IterMut` behaves like the `&mut [T]` it was built from.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> IterMut<'a, T> {
+    fn new(data: &'a mut [T], shape: &[usize], strides: &[usize], offset: usize) -> Self {
+        let seen = (!is_non_overlapping(shape, strides)).then(|| vec![false; data.len()]);
+        IterMut {
+            len: data.len(),
+            data: NonNull::from(data).cast(),
+            positions: Positions::new(shape, strides, offset),
+            seen,
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let i = self.positions.next()?;
+        let repeated = self
+            .seen
+            .as_mut()
+            .is_some_and(|seen| i < seen.len() && std::mem::replace(&mut seen[i], true));
+        if i >= self.len || repeated {
+            self.len = 0;
+            return None;
+        }
+        // SAFETY: `data` comes from a `&'a mut [T]` of `len` elements, which
+        // this iterator borrows exclusively for `'a`, and `i < len`. Every
+        // position is handed out at most once: a non-overlapping layout never
+        // maps two logical indices to the same position, and otherwise `seen`
+        // stops iteration at the first repeat.
+        Some(unsafe { &mut *self.data.as_ptr().add(i) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.positions.len()))
+    }
+}
+
+/// Elements paired with their multi-index, in logical order.
+pub struct IndexedIter<'a, T> {
+    data: &'a [T],
+    positions: Positions,
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = (Vec<usize>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next_indexed()
+            .map(|(index, i)| (index, &self.data[i]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
+
+/// Sub-views obtained by fixing successive indices along one axis.
+pub struct AxisIter<'a, T> {
+    data: &'a [T],
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    offset: usize,
+    stride: usize,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for AxisIter<'a, T> {
+    type Item = TensorView<'a, T>;
+
+    fn next(&mut self) -> Option<TensorView<'a, T>> {
+        if self.index == self.len {
+            return None;
+        }
+        let view = TensorView {
+            data: self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset + self.index * self.stride,
+        };
+        self.index += 1;
+        Some(view)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for AxisIter<'_, T> {}
+
+/// Views sharing one layout whose starting offsets follow `starts`; used for
+/// both lanes and windows.
+pub struct StridedViews<'a, T> {
+    data: &'a [T],
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    starts: Positions,
+}
+
+impl<'a, T> Iterator for StridedViews<'a, T> {
+    type Item = TensorView<'a, T>;
+
+    fn next(&mut self) -> Option<TensorView<'a, T>> {
+        self.starts.next().map(|offset| TensorView {
+            data: self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.starts.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for StridedViews<'_, T> {}
+
+/// 1-D views along an axis, one per index of the remaining axes.
+pub type Lanes<'a, T> = StridedViews<'a, T>;
+
+/// Overlapping views of a fixed window shape, sliding by one along every axis.
+pub type Windows<'a, T> = StridedViews<'a, T>;
+
+/// Whether distinct logical indices always map to distinct positions. This
+/// holds when, with the axes ordered by stride, each stride exceeds the
+/// largest offset reachable through the smaller ones.
+fn is_non_overlapping(shape: &[usize], strides: &[usize]) -> bool {
+    if shape.contains(&0) {
+        return true;
+    }
+    let mut axes: Vec<(usize, usize)> = strides
+        .iter()
+        .zip(shape)
+        .filter(|&(_, &n)| n > 1)
+        .map(|(&s, &n)| (s, n))
+        .collect();
+    axes.sort_unstable();
+    let mut extent = 0usize;
+    for (stride, len) in axes {
+        if stride <= extent {
+            return false;
+        }
+        match (len - 1)
+            .checked_mul(stride)
+            .and_then(|reach| extent.checked_add(reach))
+        {
+            Some(reach) => extent = reach,
+            None => return false,
+        }
+    }
+    true
+}
+
+fn check_axis(axis: usize, rank: usize) -> Result<()> {
+    if axis >= rank {
+        return Err(TensorError::InvalidAxis { axis, rank });
+    }
+    Ok(())
+}
+
+fn without_axis(values: &[usize], axis: usize) -> Vec<usize> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != axis)
+        .map(|(_, &v)| v)
+        .collect()
+}
+
+impl<'data, T> TensorView<'data, T> {
+    pub fn iter(&self) -> Iter<'data, T> {
+        Iter {
+            data: self.data,
+            positions: Positions::new(&self.shape, &self.strides, self.offset),
+        }
+    }
+
+    pub fn indexed_iter(&self) -> IndexedIter<'data, T> {
+        IndexedIter {
+            data: self.data,
+            positions: Positions::new(&self.shape, &self.strides, self.offset),
+        }
+    }
+
+    /// Iterates over the sub-views obtained by fixing each index along `axis`;
+    /// every view has rank one lower than `self`.
+    pub fn axis_iter(&self, axis: usize) -> Result<AxisIter<'data, T>> {
+        check_axis(axis, self.shape.len())?;
+        Ok(AxisIter {
+            data: self.data,
+            shape: without_axis(&self.shape, axis),
+            strides: without_axis(&self.strides, axis),
+            offset: self.offset,
+            stride: self.strides[axis],
+            index: 0,
+            len: self.shape[axis],
+        })
+    }
+
+    /// Iterates over every 1-D lane running along `axis`.
+    pub fn lanes(&self, axis: usize) -> Result<Lanes<'data, T>> {
+        check_axis(axis, self.shape.len())?;
+        Ok(StridedViews {
+            data: self.data,
+            shape: vec![self.shape[axis]],
+            strides: vec![self.strides[axis]],
+            starts: Positions::new(
+                &without_axis(&self.shape, axis),
+                &without_axis(&self.strides, axis),
+                self.offset,
+            ),
+        })
+    }
+
+    /// Iterates over all windows of `window` shape, in logical order of their
+    /// first element. Yields nothing if the window is larger than the tensor
+    /// along any axis.
+    pub fn windows(&self, window: &[usize]) -> Result<Windows<'data, T>> {
+        if window.len() != self.shape.len() {
+            return Err(TensorError::RankMismatch {
+                expected: self.shape.len(),
+                actual: window.len(),
+            });
+        }
+
+        let counts: Vec<usize> = self
+            .shape
+            .iter()
+            .zip(window)
+            .map(|(&n, &w)| (n + 1).saturating_sub(w))
+            .collect();
+
+        Ok(StridedViews {
+            data: self.data,
+            shape: window.to_vec(),
+            strides: self.strides.clone(),
+            starts: Positions::new(&counts, &self.strides, self.offset),
+        })
+    }
+}
+
+impl<T> TensorViewMut<'_, T> {
+    /// A shared view of the same elements.
+    pub fn view(&self) -> TensorView<'_, T> {
+        TensorView {
+            data: self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.view().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.data, &self.shape, &self.strides, self.offset)
+    }
+
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        self.view().indexed_iter()
+    }
+
+    pub fn axis_iter(&self, axis: usize) -> Result<AxisIter<'_, T>> {
+        self.view().axis_iter(axis)
+    }
+
+    pub fn lanes(&self, axis: usize) -> Result<Lanes<'_, T>> {
+        self.view().lanes(axis)
+    }
+
+    pub fn windows(&self, window: &[usize]) -> Result<Windows<'_, T>> {
+        self.view().windows(window)
+    }
+}
+
+impl<T> Tensor<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: &self.data,
+            positions: self.positions(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.data, &self.shape, &self.strides, self.offset)
+    }
+
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            data: &self.data,
+            positions: self.positions(),
+        }
+    }
+
+    pub fn axis_iter(&self, axis: usize) -> Result<AxisIter<'_, T>> {
+        self.view().axis_iter(axis)
+    }
+
+    pub fn lanes(&self, axis: usize) -> Result<Lanes<'_, T>> {
+        self.view().lanes(axis)
+    }
+
+    pub fn windows(&self, window: &[usize]) -> Result<Windows<'_, T>> {
+        self.view().windows(window)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Tensor<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Tensor<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
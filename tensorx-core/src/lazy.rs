@@ -0,0 +1,433 @@
+//! Opt-in lazy evaluation with elementwise fusion.
+//!
+//! A [`LazyTensor`] records elementwise and broadcasting operations into an
+//! expression graph instead of running them. Evaluation compiles the graph
+//! into a small register program with one instruction per distinct node, so
+//! shared subexpressions are computed once, and runs it once per output
+//! element, reading every input through its own broadcast positions. A chain
+//! such as `((a * b) + c).relu().exp()` thus makes a single pass over memory
+//! and allocates only its result. Each node applies exactly the scalar operation of its
+//! eager counterpart, so results match eager evaluation bit for bit.
+
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::rc::Rc;
+
+use crate::broadcast::broadcast_shapes;
+use crate::element::{Element, Float, Num};
+use crate::error::Result;
+use crate::math;
+use crate::memory::AlignedVec;
+use crate::tensor::{Positions, Tensor, compute_strides};
+
+type UnaryFn<T> = Rc<dyn Fn(T) -> T>;
+type BinaryFn<T> = Rc<dyn Fn(T, T) -> T>;
+
+enum Op<T> {
+    Input(Rc<Tensor<T>>),
+    /// A scalar operand, which broadcasts against anything.
+    Scalar(T),
+    Unary(UnaryFn<T>),
+    Binary(BinaryFn<T>),
+}
+
+struct Node<T> {
+    op: Op<T>,
+    args: Vec<Rc<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    fn new(op: Op<T>, args: Vec<Rc<Node<T>>>) -> Rc<Self> {
+        Rc::new(Self { op, args })
+    }
+}
+
+impl<T> Drop for Node<T> {
+    /// Unlinks uniquely owned operands iteratively, so that dropping a long
+    /// chain of operations does not overflow the stack.
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.args);
+        while let Some(node) = stack.pop() {
+            if let Ok(mut node) = Rc::try_unwrap(node) {
+                stack.append(&mut node.args);
+            }
+        }
+    }
+}
+
+/// An expression flattened into its distinct nodes, operands before the
+/// nodes using them, so shared subexpressions appear once. The root is last.
+struct Graph<'a, T> {
+    nodes: Vec<&'a Node<T>>,
+    /// Operands of each node, as indices into `nodes`.
+    args: Vec<Vec<usize>>,
+}
+
+impl<'a, T: Element> Graph<'a, T> {
+    fn new(root: &'a Node<T>) -> Self {
+        let mut index: HashMap<*const Node<T>, usize> = HashMap::new();
+        let (mut nodes, mut args) = (Vec::new(), Vec::new());
+        let mut stack = vec![(root, false)];
+        while let Some((node, expanded)) = stack.pop() {
+            let key: *const Node<T> = node;
+            if index.contains_key(&key) {
+                continue;
+            }
+            if expanded {
+                args.push(node.args.iter().map(|a| index[&Rc::as_ptr(a)]).collect());
+                index.insert(key, nodes.len());
+                nodes.push(node);
+            } else {
+                stack.push((node, true));
+                stack.extend(node.args.iter().map(|a| (a.as_ref(), false)));
+            }
+        }
+        Self { nodes, args }
+    }
+
+    /// Shape of the result, broadcasting operands in the order eager
+    /// evaluation would.
+    fn shape(&self) -> Result<Vec<usize>> {
+        let mut shapes: Vec<Vec<usize>> = Vec::with_capacity(self.nodes.len());
+        for (node, args) in self.nodes.iter().zip(&self.args) {
+            let shape = match (&node.op, args.as_slice()) {
+                (Op::Input(t), _) => t.shape.clone(),
+                (Op::Binary(_), &[a, b]) => broadcast_shapes(&shapes[a], &shapes[b])?,
+                (Op::Unary(_), &[a]) => shapes[a].clone(),
+                (Op::Scalar(_), _) => Vec::new(),
+                _ => unreachable!("operand count matches the operation"),
+            };
+            shapes.push(shape);
+        }
+        Ok(shapes.pop().unwrap_or_default())
+    }
+
+    /// One instruction per node, each writing the register of its own index,
+    /// for an output of `shape`.
+    fn compile(&self, shape: &[usize]) -> Result<Vec<Instruction<'a, T>>> {
+        self.nodes
+            .iter()
+            .zip(&self.args)
+            .map(|(node, args)| {
+                Ok(match (&node.op, args.as_slice()) {
+                    (Op::Input(t), _) => Instruction::Load(&t.data, t.broadcast_positions(shape)?),
+                    (Op::Unary(f), &[a]) => Instruction::Unary(f.as_ref(), a),
+                    (Op::Binary(f), &[a, b]) => Instruction::Binary(f.as_ref(), a, b),
+                    (Op::Scalar(x), _) => Instruction::Push(*x),
+                    _ => unreachable!("operand count matches the operation"),
+                })
+            })
+            .collect()
+    }
+}
+
+enum Instruction<'a, T> {
+    Load(&'a [T], Positions),
+    Push(T),
+    Unary(&'a dyn Fn(T) -> T, usize),
+    Binary(&'a dyn Fn(T, T) -> T, usize, usize),
+}
+
+/// A deferred elementwise expression over tensors; see the module docs.
+///
+/// The expression is evaluated on [`eval`](LazyTensor::eval) or the first
+/// data access, and the result is cached. Shape errors surface at that point
+/// too. Expressions built on an evaluated `LazyTensor` read its cached result
+/// instead of recomputing it.
+pub struct LazyTensor<T> {
+    node: Rc<Node<T>>,
+    value: OnceCell<Rc<Tensor<T>>>,
+}
+
+impl<T> Clone for LazyTensor<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: Rc::clone(&self.node),
+            value: OnceCell::new(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for LazyTensor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyTensor")
+            .field("evaluated", &self.value.get().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> From<Tensor<T>> for LazyTensor<T> {
+    fn from(t: Tensor<T>) -> Self {
+        Self::with_node(Node::new(Op::Input(Rc::new(t)), Vec::new()))
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Starts a lazy expression from a copy of this tensor.
+    pub fn lazy(&self) -> LazyTensor<T> {
+        self.clone().into_lazy()
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Starts a lazy expression from this tensor without copying it.
+    pub fn into_lazy(self) -> LazyTensor<T> {
+        LazyTensor::from(self)
+    }
+}
+
+impl<T> LazyTensor<T> {
+    fn with_node(node: Rc<Node<T>>) -> Self {
+        Self {
+            node,
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Whether the expression has been evaluated yet.
+    pub fn is_evaluated(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// The node to build on: the cached result if there is one, otherwise
+    /// the expression itself.
+    fn operand(&self) -> Rc<Node<T>> {
+        match self.value.get() {
+            Some(value) => Node::new(Op::Input(Rc::clone(value)), Vec::new()),
+            None => Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<T: Element> LazyTensor<T> {
+    /// Records `f` applied to every element.
+    pub fn map(&self, f: impl Fn(T) -> T + 'static) -> Self {
+        Self::with_node(Node::new(Op::Unary(Rc::new(f)), vec![self.operand()]))
+    }
+
+    /// Records `f` combining `self` and `rhs` elementwise after broadcasting.
+    pub fn zip_with(&self, rhs: &Self, f: impl Fn(T, T) -> T + 'static) -> Self {
+        Self::with_node(Node::new(
+            Op::Binary(Rc::new(f)),
+            vec![self.operand(), rhs.operand()],
+        ))
+    }
+
+    fn zip_scalar(&self, scalar: T, f: impl Fn(T, T) -> T + 'static, scalar_first: bool) -> Self {
+        let scalar = Node::new(Op::Scalar(scalar), Vec::new());
+        let args = if scalar_first {
+            vec![scalar, self.operand()]
+        } else {
+            vec![self.operand(), scalar]
+        };
+        Self::with_node(Node::new(Op::Binary(Rc::new(f)), args))
+    }
+
+    /// Shape of the result, inferred without evaluating.
+    pub fn shape(&self) -> Result<Vec<usize>> {
+        match self.value.get() {
+            Some(value) => Ok(value.shape.clone()),
+            None => Graph::new(&self.node).shape(),
+        }
+    }
+
+    /// Evaluates the expression in one fused pass, or returns the cached
+    /// result of an earlier evaluation.
+    pub fn eval(&self) -> Result<&Tensor<T>> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        let value = self.evaluate()?;
+        Ok(self.value.get_or_init(|| Rc::new(value)))
+    }
+
+    /// Evaluates the expression and returns the result by value.
+    pub fn into_tensor(mut self) -> Result<Tensor<T>> {
+        match self.value.take() {
+            Some(value) => Ok(Rc::try_unwrap(value).unwrap_or_else(|value| (*value).clone())),
+            None => self.evaluate(),
+        }
+    }
+
+    pub fn get_data(&self) -> Result<&[T]> {
+        Ok(self.eval()?.get_data())
+    }
+
+    pub fn get(&self, indices: &[usize]) -> Result<&T> {
+        self.eval()?.get(indices)
+    }
+
+    fn evaluate(&self) -> Result<Tensor<T>> {
+        let graph = Graph::new(&self.node);
+        let shape = graph.shape()?;
+        let mut program = graph.compile(&shape)?;
+
+        let numel = shape.iter().product();
+        let mut data = AlignedVec::with_capacity(numel);
+        let mut registers = vec![T::default(); program.len()];
+        for _ in 0..numel {
+            for (r, instruction) in program.iter_mut().enumerate() {
+                registers[r] = match instruction {
+                    Instruction::Load(values, positions) => {
+                        values[positions.next().expect("one position per element")]
+                    }
+                    Instruction::Push(x) => *x,
+                    Instruction::Unary(f, a) => f(registers[*a]),
+                    Instruction::Binary(f, a, b) => f(registers[*a], registers[*b]),
+                };
+            }
+            data.push(registers[program.len() - 1]);
+        }
+
+        Ok(Tensor {
+            data,
+            strides: compute_strides(&shape),
+            shape,
+            offset: 0,
+        })
+    }
+}
+
+impl<T: Float> LazyTensor<T> {
+    pub fn relu(&self) -> Self {
+        self.map(math::relu)
+    }
+
+    pub fn sigmoid(&self) -> Self {
+        self.map(math::sigmoid)
+    }
+
+    pub fn tanh(&self) -> Self {
+        self.map(T::tanh)
+    }
+
+    pub fn exp(&self) -> Self {
+        self.map(T::exp)
+    }
+
+    pub fn log(&self) -> Self {
+        self.map(T::ln)
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.map(T::sqrt)
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn powf(&self, exponent: T) -> Self {
+        self.map(move |x| T::powf(x, exponent))
+    }
+}
+
+macro_rules! impl_lazy_op {
+    ($Op:ident, $op:ident) => {
+        impl<T: Num> $Op<&LazyTensor<T>> for &LazyTensor<T> {
+            type Output = LazyTensor<T>;
+
+            fn $op(self, rhs: &LazyTensor<T>) -> LazyTensor<T> {
+                self.zip_with(rhs, $Op::$op)
+            }
+        }
+
+        impl<T: Num> $Op<LazyTensor<T>> for &LazyTensor<T> {
+            type Output = LazyTensor<T>;
+
+            fn $op(self, rhs: LazyTensor<T>) -> LazyTensor<T> {
+                self.$op(&rhs)
+            }
+        }
+
+        impl<T: Num> $Op<&LazyTensor<T>> for LazyTensor<T> {
+            type Output = LazyTensor<T>;
+
+            fn $op(self, rhs: &LazyTensor<T>) -> LazyTensor<T> {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl<T: Num> $Op for LazyTensor<T> {
+            type Output = LazyTensor<T>;
+
+            fn $op(self, rhs: LazyTensor<T>) -> LazyTensor<T> {
+                (&self).$op(&rhs)
+            }
+        }
+    };
+}
+
+impl_lazy_op!(Add, add);
+impl_lazy_op!(Sub, sub);
+impl_lazy_op!(Mul, mul);
+impl_lazy_op!(Div, div);
+
+macro_rules! impl_lazy_scalar_op {
+    ($Op:ident, $op:ident; $($t:ty),*) => {
+        $(
+            impl $Op<$t> for &LazyTensor<$t> {
+                type Output = LazyTensor<$t>;
+
+                fn $op(self, rhs: $t) -> LazyTensor<$t> {
+                    self.zip_scalar(rhs, $Op::$op, false)
+                }
+            }
+
+            impl $Op<$t> for LazyTensor<$t> {
+                type Output = LazyTensor<$t>;
+
+                fn $op(self, rhs: $t) -> LazyTensor<$t> {
+                    (&self).$op(rhs)
+                }
+            }
+
+            impl $Op<&LazyTensor<$t>> for $t {
+                type Output = LazyTensor<$t>;
+
+                fn $op(self, rhs: &LazyTensor<$t>) -> LazyTensor<$t> {
+                    rhs.zip_scalar(self, $Op::$op, true)
+                }
+            }
+
+            impl $Op<LazyTensor<$t>> for $t {
+                type Output = LazyTensor<$t>;
+
+                fn $op(self, rhs: LazyTensor<$t>) -> LazyTensor<$t> {
+                    self.$op(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lazy_scalar_ops {
+    ($($t:ty),*) => {
+        impl_lazy_scalar_op!(Add, add; $($t),*);
+        impl_lazy_scalar_op!(Sub, sub; $($t),*);
+        impl_lazy_scalar_op!(Mul, mul; $($t),*);
+        impl_lazy_scalar_op!(Div, div; $($t),*);
+    };
+}
+
+impl_lazy_scalar_ops!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl<T: Element + Neg<Output = T>> Neg for &LazyTensor<T> {
+    type Output = LazyTensor<T>;
+
+    fn neg(self) -> LazyTensor<T> {
+        self.map(|x| -x)
+    }
+}
+
+impl<T: Element + Neg<Output = T>> Neg for LazyTensor<T> {
+    type Output = LazyTensor<T>;
+
+    fn neg(self) -> LazyTensor<T> {
+        -&self
+    }
+}
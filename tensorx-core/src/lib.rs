@@ -1,3 +1,30 @@
+mod approx;
+pub mod blas;
+pub mod broadcast;
+pub mod complex;
+mod cumulative;
+pub mod display;
+pub mod einsum;
+pub mod element;
 pub mod error;
+pub mod fft;
+pub mod iter;
+pub mod lazy;
+pub mod linalg;
+mod macros;
+mod map;
+mod math;
+pub mod memory;
+pub mod nested;
+mod ops;
+pub mod pad;
+mod product;
+pub mod quantize;
+pub mod ranked;
+mod serialize;
+pub mod sort;
+pub mod sparse;
+mod special;
+pub mod stats;
 pub mod tensor;
 pub mod view;
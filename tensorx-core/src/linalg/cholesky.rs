@@ -0,0 +1,38 @@
+use super::{Matrix, ensure_square, rebatch, unbatch};
+use crate::element::Float;
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+pub(crate) fn factor<T: Float>(a: &Matrix<T>) -> Result<Matrix<T>> {
+    ensure_square(a)?;
+    let n = a.rows;
+    let mut l = Matrix::zeros(n, n);
+    for j in 0..n {
+        let mut d = a[(j, j)];
+        for k in 0..j {
+            d -= l[(j, k)] * l[(j, k)];
+        }
+        if d <= T::zero() || d.is_nan() {
+            return Err(TensorError::NotPositiveDefinite);
+        }
+        let d = d.sqrt();
+        l[(j, j)] = d;
+        for i in j + 1..n {
+            let mut s = a[(i, j)];
+            for k in 0..j {
+                s -= l[(i, k)] * l[(j, k)];
+            }
+            l[(i, j)] = s / d;
+        }
+    }
+    Ok(l)
+}
+
+/// Lower-triangular `l` with `a = l · lᵀ` for each symmetric positive
+/// definite matrix in `a`. Only the lower triangle of `a` is read.
+pub fn cholesky<T: Float>(a: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let n = a.shape[a.shape.len() - 2];
+    let factors = matrices.iter().map(factor).collect::<Result<Vec<_>>>()?;
+    Ok(rebatch(&batch, n, n, factors))
+}
@@ -0,0 +1,121 @@
+use super::{Matrix, batch_tensor, ensure_square, rebatch, unbatch};
+use crate::element::Float;
+use crate::error::Result;
+use crate::memory::AlignedVec;
+use crate::tensor::Tensor;
+
+const MAX_SWEEPS: usize = 100;
+
+/// Eigendecomposition of a symmetric matrix,
+/// `a = eigenvectors · diag(eigenvalues) · eigenvectorsᵀ`.
+///
+/// `eigenvalues` has shape `[..., n]` in ascending order and column `i` of
+/// `eigenvectors` (`[..., n, n]`) is the unit eigenvector for eigenvalue `i`.
+#[derive(Debug, Clone)]
+pub struct Eigh<T> {
+    pub eigenvalues: Tensor<T>,
+    pub eigenvectors: Tensor<T>,
+}
+
+/// Rotates columns `p` and `q` of `m` by the Givens rotation `(c, s)`.
+pub(crate) fn rotate_columns<T: Float>(m: &mut Matrix<T>, p: usize, q: usize, c: T, s: T) {
+    for k in 0..m.rows {
+        let (x, y) = (m[(k, p)], m[(k, q)]);
+        m[(k, p)] = c * x - s * y;
+        m[(k, q)] = s * x + c * y;
+    }
+}
+
+fn rotate_rows<T: Float>(m: &mut Matrix<T>, p: usize, q: usize, c: T, s: T) {
+    for k in 0..m.cols {
+        let (x, y) = (m[(p, k)], m[(q, k)]);
+        m[(p, k)] = c * x - s * y;
+        m[(q, k)] = s * x + c * y;
+    }
+}
+
+/// Cyclic Jacobi eigenvalue iteration, returning unsorted eigenvalues and
+/// the matching eigenvectors as columns.
+fn jacobi<T: Float>(a: &Matrix<T>) -> (Vec<T>, Matrix<T>) {
+    let n = a.rows;
+    let half = T::one() / (T::one() + T::one());
+    let mut a = {
+        let t = a.transpose();
+        let mut sym = a.clone();
+        for (x, y) in sym.data.iter_mut().zip(t.data) {
+            *x = (*x + y) * half;
+        }
+        sym
+    };
+    let mut v = Matrix::identity(n);
+    let total = a.data.iter().fold(T::zero(), |acc, &x| acc + x * x);
+    let tol = T::epsilon() * T::epsilon() * total;
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = T::zero();
+        for p in 0..n {
+            for q in p + 1..n {
+                off += a[(p, q)] * a[(p, q)];
+            }
+        }
+        if off <= tol {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[(p, q)];
+                if apq == T::zero() {
+                    continue;
+                }
+                let theta = (a[(q, q)] - a[(p, p)]) * half / apq;
+                let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let t = if theta < T::zero() { -t } else { t };
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                rotate_columns(&mut a, p, q, c, s);
+                rotate_rows(&mut a, p, q, c, s);
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[(i, i)]).collect(), v)
+}
+
+/// Reorders the columns of `m` so that column `i` is the old `order[i]`.
+pub(crate) fn permute_columns<T: Float>(m: &Matrix<T>, order: &[usize]) -> Matrix<T> {
+    let mut out = Matrix::zeros(m.rows, order.len());
+    for r in 0..m.rows {
+        for (c, &src) in order.iter().enumerate() {
+            out[(r, c)] = m[(r, src)];
+        }
+    }
+    out
+}
+
+/// Eigenvalues and eigenvectors of each symmetric matrix in `a`, computed
+/// with the cyclic Jacobi method. Only the symmetric part `(a + aᵀ) / 2` is
+/// used.
+pub fn eigh<T: Float>(a: &Tensor<T>) -> Result<Eigh<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let n = a.shape[a.shape.len() - 1];
+
+    let mut values = AlignedVec::with_capacity(matrices.len() * n);
+    let mut vectors = Vec::with_capacity(matrices.len());
+    for matrix in matrices {
+        ensure_square(&matrix)?;
+        let (w, v) = jacobi(&matrix);
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap_or(std::cmp::Ordering::Equal));
+        values.extend(order.iter().map(|&i| w[i]));
+        vectors.push(permute_columns(&v, &order));
+    }
+
+    let mut shape = batch.clone();
+    shape.push(n);
+    Ok(Eigh {
+        eigenvalues: batch_tensor(&shape, values),
+        eigenvectors: rebatch(&batch, n, n, vectors),
+    })
+}
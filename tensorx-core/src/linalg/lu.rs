@@ -0,0 +1,233 @@
+use super::{
+    Matrix, batch_tensor, ensure_square, rebatch, rebatch_solution, unbatch, unbatch_system,
+};
+use crate::element::Float;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::Tensor;
+
+/// LU decomposition with partial pivoting, `a = p · l · u`.
+///
+/// For an `m x n` input with `k = min(m, n)`, `p` is `m x m`, `l` is `m x k`
+/// with a unit diagonal and `u` is `k x n`.
+#[derive(Debug, Clone)]
+pub struct Lu<T> {
+    pub p: Tensor<T>,
+    pub l: Tensor<T>,
+    pub u: Tensor<T>,
+}
+
+/// Packed in-place factorisation: `l` below the diagonal, `u` on and above
+/// it, plus the row permutation and its sign.
+pub(crate) struct Factored<T> {
+    pub(crate) lu: Matrix<T>,
+    pub(crate) perm: Vec<usize>,
+    pub(crate) sign: T,
+    /// Pivots at or below this magnitude count as zero when solving.
+    tol: T,
+}
+
+impl<T: Float> Factored<T> {
+    pub(crate) fn new(mut a: Matrix<T>) -> Self {
+        let (m, n) = (a.rows, a.cols);
+        let tol = a.max_abs() * T::epsilon() * T::from_usize(m.max(n));
+        let mut perm: Vec<usize> = (0..m).collect();
+        let mut sign = T::one();
+
+        for j in 0..m.min(n) {
+            let pivot = (j..m)
+                .max_by(|&x, &y| {
+                    a[(x, j)]
+                        .abs()
+                        .partial_cmp(&a[(y, j)].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(j);
+            if pivot != j {
+                for c in 0..n {
+                    a.data.swap(j * n + c, pivot * n + c);
+                }
+                perm.swap(j, pivot);
+                sign = -sign;
+            }
+
+            let d = a[(j, j)];
+            if d == T::zero() {
+                continue;
+            }
+            for i in j + 1..m {
+                let f = a[(i, j)] / d;
+                a[(i, j)] = f;
+                for c in j + 1..n {
+                    let u = a[(j, c)];
+                    a[(i, c)] -= f * u;
+                }
+            }
+        }
+
+        Self {
+            lu: a,
+            perm,
+            sign,
+            tol,
+        }
+    }
+
+    /// Whether some pivot is negligible relative to the largest entry of the
+    /// input, so that solving would only amplify rounding errors.
+    fn is_singular(&self) -> bool {
+        (0..self.lu.rows.min(self.lu.cols)).any(|i| {
+            let d = self.lu[(i, i)];
+            d.abs() <= self.tol || d.is_nan()
+        })
+    }
+
+    /// Solves `a x = b` for a square, non-singular `a`.
+    pub(crate) fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.is_singular() {
+            return Err(TensorError::Singular);
+        }
+        let n = self.lu.rows;
+        let mut x = Matrix::zeros(n, b.cols);
+        for i in 0..n {
+            for c in 0..b.cols {
+                x[(i, c)] = b[(self.perm[i], c)];
+            }
+        }
+        for c in 0..b.cols {
+            for i in 0..n {
+                let mut s = x[(i, c)];
+                for k in 0..i {
+                    s -= self.lu[(i, k)] * x[(k, c)];
+                }
+                x[(i, c)] = s;
+            }
+            for i in (0..n).rev() {
+                let mut s = x[(i, c)];
+                for k in i + 1..n {
+                    s -= self.lu[(i, k)] * x[(k, c)];
+                }
+                x[(i, c)] = s / self.lu[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    fn det(&self) -> T {
+        (0..self.lu.rows).fold(self.sign, |acc, i| acc * self.lu[(i, i)])
+    }
+}
+
+/// Computes the pivoted LU decomposition of each matrix in `a`.
+pub fn lu<T: Float>(a: &Tensor<T>) -> Result<Lu<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let rank = a.shape.len();
+    let (m, n) = (a.shape[rank - 2], a.shape[rank - 1]);
+    let k = m.min(n);
+
+    let (mut ps, mut ls, mut us) = (Vec::new(), Vec::new(), Vec::new());
+    for matrix in matrices {
+        let f = Factored::new(matrix);
+        let mut p = Matrix::zeros(m, m);
+        for (i, &row) in f.perm.iter().enumerate() {
+            p[(row, i)] = T::one();
+        }
+        let mut l = Matrix::zeros(m, k);
+        let mut u = Matrix::zeros(k, n);
+        for i in 0..m {
+            for j in 0..n {
+                if j < i && j < k {
+                    l[(i, j)] = f.lu[(i, j)];
+                } else if i < k {
+                    u[(i, j)] = f.lu[(i, j)];
+                }
+            }
+            if i < k {
+                l[(i, i)] = T::one();
+            }
+        }
+        ps.push(p);
+        ls.push(l);
+        us.push(u);
+    }
+
+    Ok(Lu {
+        p: rebatch(&batch, m, m, ps),
+        l: rebatch(&batch, m, k, ls),
+        u: rebatch(&batch, k, n, us),
+    })
+}
+
+/// Solves `a x = b` for square `a`. `b` is either `[..., n, k]`, a vector
+/// `[n]`, or a batch of vectors with exactly `a`'s batch shape; batch axes
+/// of matrices and the 1-D vector broadcast against `a`'s.
+pub fn solve<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, pairs, vector) = unbatch_system(a, b)?;
+    let n = a.shape[a.shape.len() - 2];
+    let k = if vector {
+        1
+    } else {
+        b.shape[b.shape.len() - 1]
+    };
+
+    let mut solutions = Vec::with_capacity(pairs.len());
+    for (a, b) in pairs {
+        ensure_square(&a)?;
+        solutions.push(Factored::new(a).solve(&b)?);
+    }
+    rebatch_solution(&batch, solutions, vector, n, k)
+}
+
+/// Inverts each square matrix in `a`.
+pub fn inv<T: Float>(a: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let n = a.shape[a.shape.len() - 2];
+
+    let mut inverses = Vec::with_capacity(matrices.len());
+    for matrix in matrices {
+        ensure_square(&matrix)?;
+        inverses.push(Factored::new(matrix).solve(&Matrix::identity(n))?);
+    }
+    Ok(rebatch(&batch, n, n, inverses))
+}
+
+/// Determinant of each square matrix in `a`, as a tensor of the batch shape.
+pub fn det<T: Float>(a: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let mut dets = AlignedVec::with_capacity(matrices.len());
+    for matrix in matrices {
+        ensure_square(&matrix)?;
+        dets.push(Factored::new(matrix).det());
+    }
+    Ok(batch_tensor(&batch, dets))
+}
+
+/// Sign and natural log of the absolute determinant, which stays finite where
+/// [`det`] would overflow. Singular matrices give a sign of 0 and a log of
+/// negative infinity.
+pub fn slogdet<T: Float>(a: &Tensor<T>) -> Result<(Tensor<T>, Tensor<T>)> {
+    let (batch, matrices) = unbatch(a)?;
+    let mut signs = AlignedVec::with_capacity(matrices.len());
+    let mut logs = AlignedVec::with_capacity(matrices.len());
+    for matrix in matrices {
+        ensure_square(&matrix)?;
+        let f = Factored::new(matrix);
+        let mut sign = f.sign;
+        let mut log = T::zero();
+        for i in 0..f.lu.rows {
+            let d = f.lu[(i, i)];
+            if d == T::zero() {
+                sign = T::zero();
+                log = T::neg_infinity();
+                break;
+            }
+            if d < T::zero() {
+                sign = -sign;
+            }
+            log += d.abs().ln();
+        }
+        signs.push(sign);
+        logs.push(log);
+    }
+    Ok((batch_tensor(&batch, signs), batch_tensor(&batch, logs)))
+}
@@ -0,0 +1,247 @@
+//! Dense linear algebra on 2-D and batched floating point tensors.
+//!
+//! Every function accepts a tensor of shape `[..., m, n]` and treats the
+//! leading axes as a batch of independent matrices. Where two operands are
+//! involved, their batch axes broadcast against each other.
+
+mod cholesky;
+mod eigen;
+mod lu;
+mod norm;
+mod qr;
+mod svd;
+
+pub use cholesky::cholesky;
+pub use eigen::{Eigh, eigh};
+pub use lu::{Lu, det, inv, lu, slogdet, solve};
+pub use norm::{MatrixNorm, norm};
+pub use qr::{Qr, lstsq, qr};
+pub use svd::{Svd, cond, matrix_rank, pinv, svd};
+
+use crate::broadcast::broadcast_shapes;
+use crate::element::Float;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+
+/// Row-major dense matrix used as scratch space by the decompositions.
+#[derive(Debug, Clone)]
+pub(crate) struct Matrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) data: Vec<T>,
+}
+
+impl<T: Float> Matrix<T> {
+    pub(crate) fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    pub(crate) fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::one();
+        }
+        m
+    }
+
+    pub(crate) fn transpose(&self) -> Self {
+        let mut t = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    pub(crate) fn matmul(&self, rhs: &Self) -> Self {
+        let mut out = Self::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..rhs.cols {
+                    out[(i, j)] += a * rhs[(k, j)];
+                }
+            }
+        }
+        out
+    }
+
+    /// Copies out the `rows x cols` block starting at the top-left corner.
+    pub(crate) fn block(&self, rows: usize, cols: usize) -> Self {
+        let mut out = Self::zeros(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                out[(i, j)] = self[(i, j)];
+            }
+        }
+        out
+    }
+
+    pub(crate) fn max_abs(&self) -> T {
+        self.data.iter().fold(
+            T::zero(),
+            |acc, &x| if x.abs() > acc { x.abs() } else { acc },
+        )
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// Splits a `[..., rows, cols]` tensor into its batch shape and matrices.
+pub(crate) fn unbatch<T: Float>(t: &Tensor<T>) -> Result<(Vec<usize>, Vec<Matrix<T>>)> {
+    let rank = t.shape.len();
+    if rank < 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: rank,
+        });
+    }
+    let batch = t.shape[..rank - 2].to_vec();
+    let matrices = unbatch_to(t, &batch)?;
+    Ok((batch, matrices))
+}
+
+/// Like [`unbatch`], but first broadcasts the batch axes to `batch`.
+pub(crate) fn unbatch_to<T: Float>(t: &Tensor<T>, batch: &[usize]) -> Result<Vec<Matrix<T>>> {
+    let rank = t.shape.len();
+    let (rows, cols) = (t.shape[rank - 2], t.shape[rank - 1]);
+
+    let mut shape = batch.to_vec();
+    shape.extend([rows, cols]);
+    let values: Vec<T> = t.broadcast_to(&shape)?.iter().copied().collect();
+
+    if rows * cols == 0 {
+        let count = batch.iter().product();
+        return Ok(vec![Matrix::zeros(rows, cols); count]);
+    }
+    Ok(values
+        .chunks(rows * cols)
+        .map(|chunk| Matrix {
+            rows,
+            cols,
+            data: chunk.to_vec(),
+        })
+        .collect())
+}
+
+/// Stacks per-matrix results back into a `[batch..., rows, cols]` tensor.
+pub(crate) fn rebatch<T: Float>(
+    batch: &[usize],
+    rows: usize,
+    cols: usize,
+    matrices: Vec<Matrix<T>>,
+) -> Tensor<T> {
+    let mut shape = batch.to_vec();
+    shape.extend([rows, cols]);
+    let data = matrices.into_iter().flat_map(|m| m.data).collect();
+    Tensor {
+        data,
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+/// Builds a contiguous tensor of `shape` from per-matrix results.
+pub(crate) fn batch_tensor<T>(shape: &[usize], data: AlignedVec<T>) -> Tensor<T> {
+    Tensor {
+        data,
+        shape: shape.to_vec(),
+        strides: compute_strides(shape),
+        offset: 0,
+    }
+}
+
+pub(crate) fn ensure_square<T>(m: &Matrix<T>) -> Result<()> {
+    if m.rows != m.cols {
+        return Err(TensorError::ShapeMismatch {
+            expected: vec![m.rows, m.rows],
+            actual: vec![m.rows, m.cols],
+        });
+    }
+    Ok(())
+}
+
+/// Pairs up the matrices of a system `a x = b`, where `b` is either a batch
+/// of matrices `[..., n, k]` or of vectors `[..., n]`. `b` is read as vectors
+/// only if it is 1-D or its shape is exactly `a`'s without the last axis.
+/// Returns the broadcast batch shape, the pairs, and whether `b` was a
+/// vector.
+#[allow(clippy::type_complexity)]
+pub(crate) fn unbatch_system<T: Float>(
+    a: &Tensor<T>,
+    b: &Tensor<T>,
+) -> Result<(Vec<usize>, Vec<(Matrix<T>, Matrix<T>)>, bool)> {
+    let a_rank = a.shape.len();
+    if a_rank < 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: a_rank,
+        });
+    }
+
+    let vector = b.shape.len() == 1 || b.shape[..] == a.shape[..a_rank - 1];
+    let b = if vector {
+        let mut shape = b.shape.clone();
+        shape.push(1);
+        b.reshape(shape)?
+    } else {
+        b.clone()
+    };
+    let b_rank = b.shape.len();
+    if b_rank < 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: b_rank,
+        });
+    }
+    if b.shape[b_rank - 2] != a.shape[a_rank - 2] {
+        return Err(TensorError::DimensionalMismatch {
+            expected: a.shape[a_rank - 2],
+            actual: b.shape[b_rank - 2],
+        });
+    }
+
+    let batch = broadcast_shapes(&a.shape[..a_rank - 2], &b.shape[..b_rank - 2])?;
+    let pairs = unbatch_to(a, &batch)?
+        .into_iter()
+        .zip(unbatch_to(&b, &batch)?)
+        .collect();
+    Ok((batch, pairs, vector))
+}
+
+/// Inverse of [`unbatch_system`] for the solutions.
+pub(crate) fn rebatch_solution<T: Float>(
+    batch: &[usize],
+    solutions: Vec<Matrix<T>>,
+    vector: bool,
+    rows: usize,
+    cols: usize,
+) -> Result<Tensor<T>> {
+    let out = rebatch(batch, rows, cols, solutions);
+    if vector {
+        let mut shape = batch.to_vec();
+        shape.push(rows);
+        out.reshape(shape)
+    } else {
+        Ok(out)
+    }
+}
@@ -0,0 +1,61 @@
+use super::svd::singular_values;
+use super::{Matrix, batch_tensor, unbatch};
+use crate::element::Float;
+use crate::error::Result;
+use crate::tensor::Tensor;
+
+/// Matrix norms accepted by [`norm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixNorm {
+    /// Frobenius norm, the square root of the sum of squared entries.
+    Fro,
+    /// Nuclear norm, the sum of the singular values.
+    Nuc,
+    /// Maximum absolute column sum.
+    One,
+    /// Spectral norm, the largest singular value.
+    Two,
+    /// Maximum absolute row sum.
+    Inf,
+}
+
+/// Computes the requested norm of each matrix in `a`, returning a tensor of
+/// the batch shape.
+pub fn norm<T: Float>(a: &Tensor<T>, ord: MatrixNorm) -> Result<Tensor<T>> {
+    let (batch, norms) = match ord {
+        MatrixNorm::Nuc => {
+            let (batch, values) = singular_values(a)?;
+            let sums = values
+                .iter()
+                .map(|s| s.iter().fold(T::zero(), |acc, &x| acc + x))
+                .collect();
+            (batch, sums)
+        }
+        MatrixNorm::Two => {
+            let (batch, values) = singular_values(a)?;
+            let maxima = values
+                .iter()
+                .map(|s| s.first().copied().unwrap_or(T::zero()))
+                .collect();
+            (batch, maxima)
+        }
+        MatrixNorm::Fro | MatrixNorm::One | MatrixNorm::Inf => {
+            let (batch, matrices) = unbatch(a)?;
+            (batch, matrices.iter().map(|m| entrywise(m, ord)).collect())
+        }
+    };
+    Ok(batch_tensor(&batch, norms))
+}
+
+/// Norms computable directly from the entries, without an SVD.
+fn entrywise<T: Float>(m: &Matrix<T>, ord: MatrixNorm) -> T {
+    match ord {
+        MatrixNorm::One => (0..m.cols)
+            .map(|j| (0..m.rows).fold(T::zero(), |acc, i| acc + m[(i, j)].abs()))
+            .fold(T::zero(), T::maximum),
+        MatrixNorm::Inf => (0..m.rows)
+            .map(|i| (0..m.cols).fold(T::zero(), |acc, j| acc + m[(i, j)].abs()))
+            .fold(T::zero(), T::maximum),
+        _ => m.data.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt(),
+    }
+}
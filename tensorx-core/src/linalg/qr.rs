@@ -0,0 +1,152 @@
+use super::{Matrix, rebatch, rebatch_solution, unbatch, unbatch_system};
+use crate::element::Float;
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+/// Reduced QR decomposition, `a = q · r`.
+///
+/// For an `m x n` input with `k = min(m, n)`, `q` is `m x k` with orthonormal
+/// columns and `r` is `k x n` upper triangular.
+#[derive(Debug, Clone)]
+pub struct Qr<T> {
+    pub q: Tensor<T>,
+    pub r: Tensor<T>,
+}
+
+/// Householder QR of a single matrix, returning the reduced factors.
+pub(crate) fn householder<T: Float>(a: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+    let (m, n) = (a.rows, a.cols);
+    let k = m.min(n);
+    let mut r = a.clone();
+    let mut q = Matrix::identity(m);
+
+    for j in 0..k {
+        let mut v: Vec<T> = (j..m).map(|i| r[(i, j)]).collect();
+        let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+        let alpha = if v[0] > T::zero() { -norm } else { norm };
+        v[0] -= alpha;
+        let vv = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+        if vv == T::zero() {
+            continue;
+        }
+        let two = T::one() + T::one();
+
+        // r <- H r, touching only rows j.. and columns j..
+        for c in j..n {
+            let dot = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * r[(i, c)]);
+            let f = two * dot / vv;
+            for i in j..m {
+                r[(i, c)] -= f * v[i - j];
+            }
+        }
+        // q <- q H, touching only columns j..
+        for row in 0..m {
+            let dot = (j..m).fold(T::zero(), |acc, i| acc + q[(row, i)] * v[i - j]);
+            let f = two * dot / vv;
+            for i in j..m {
+                q[(row, i)] -= f * v[i - j];
+            }
+        }
+        for i in j + 1..m {
+            r[(i, j)] = T::zero();
+        }
+    }
+
+    (q.block(m, k), r.block(k, n))
+}
+
+/// Fails with [`TensorError::Singular`] if the square triangular `r` is
+/// rank deficient relative to `scale`.
+fn check_rank<T: Float>(r: &Matrix<T>, scale: usize) -> Result<()> {
+    let tol = r.max_abs() * T::epsilon() * T::from_usize(scale);
+    if (0..r.rows).any(|i| r[(i, i)].abs() <= tol || r[(i, i)].is_nan()) {
+        return Err(TensorError::Singular);
+    }
+    Ok(())
+}
+
+/// Solves `r x = b` for upper triangular `r`.
+fn back_substitute<T: Float>(r: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let n = r.rows;
+    let mut x = b.clone();
+    for c in 0..b.cols {
+        for i in (0..n).rev() {
+            let mut s = x[(i, c)];
+            for k in i + 1..n {
+                s -= r[(i, k)] * x[(k, c)];
+            }
+            x[(i, c)] = s / r[(i, i)];
+        }
+    }
+    x
+}
+
+/// Solves `rᵀ x = b` for upper triangular `r`.
+fn forward_substitute_transposed<T: Float>(r: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let n = r.rows;
+    let mut x = b.clone();
+    for c in 0..b.cols {
+        for i in 0..n {
+            let mut s = x[(i, c)];
+            for k in 0..i {
+                s -= r[(k, i)] * x[(k, c)];
+            }
+            x[(i, c)] = s / r[(i, i)];
+        }
+    }
+    x
+}
+
+/// Least squares (or, if underdetermined, minimum norm) solution of a single
+/// full-rank system.
+fn lstsq_matrix<T: Float>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>> {
+    let (m, n) = (a.rows, a.cols);
+    if m >= n {
+        let (q, r) = householder(a);
+        check_rank(&r, m)?;
+        Ok(back_substitute(&r, &q.transpose().matmul(b)))
+    } else {
+        let (q, r) = householder(&a.transpose());
+        check_rank(&r, n)?;
+        Ok(q.matmul(&forward_substitute_transposed(&r, b)))
+    }
+}
+
+/// Computes the reduced QR decomposition of each matrix in `a`.
+pub fn qr<T: Float>(a: &Tensor<T>) -> Result<Qr<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let rank = a.shape.len();
+    let (m, n) = (a.shape[rank - 2], a.shape[rank - 1]);
+    let k = m.min(n);
+
+    let (qs, rs) = matrices.iter().map(householder).unzip();
+    Ok(Qr {
+        q: rebatch(&batch, m, k, qs),
+        r: rebatch(&batch, k, n, rs),
+    })
+}
+
+/// Minimises `‖a x - b‖₂` for each `m x n` matrix in `a`. When `m < n` the
+/// minimum norm solution is returned. `b` is `[..., m, k]`, `[m]`, or
+/// `[..., m]` with exactly `a`'s batch shape.
+///
+/// `a` must have full rank; rank-deficient systems fail with
+/// [`TensorError::Singular`].
+pub fn lstsq<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, pairs, vector) = unbatch_system(a, b)?;
+    let n = a.shape[a.shape.len() - 1];
+    let k = if vector {
+        1
+    } else {
+        b.shape[b.shape.len() - 1]
+    };
+
+    let solutions = pairs
+        .iter()
+        .map(|(a, b)| lstsq_matrix(a, b))
+        .collect::<Result<Vec<_>>>()?;
+    rebatch_solution(&batch, solutions, vector, n, k)
+}
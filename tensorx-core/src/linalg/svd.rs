@@ -0,0 +1,265 @@
+use super::eigen::{permute_columns, rotate_columns};
+use super::{Matrix, batch_tensor, rebatch, unbatch};
+use crate::element::Float;
+use crate::error::Result;
+use crate::memory::AlignedVec;
+use crate::tensor::Tensor;
+
+const MAX_SWEEPS: usize = 100;
+
+/// Singular value decomposition, `a = u · diag(s) · vt`.
+///
+/// For an `m x n` input with `k = min(m, n)`, `s` has shape `[..., k]` in
+/// descending order. In reduced mode `u` is `m x k` and `vt` is `k x n`; in
+/// full mode they are `m x m` and `n x n`.
+#[derive(Debug, Clone)]
+pub struct Svd<T> {
+    pub u: Tensor<T>,
+    pub s: Tensor<T>,
+    pub vt: Tensor<T>,
+}
+
+/// SVD factors of a single matrix, with `a = u · diag(s) · vᵀ`.
+pub(crate) struct Factors<T> {
+    pub(crate) u: Matrix<T>,
+    pub(crate) s: Vec<T>,
+    pub(crate) v: Matrix<T>,
+}
+
+/// One-sided (Hestenes) Jacobi SVD for `m >= n`. Returns `u` (`m x n`,
+/// orthonormal columns), descending `s` and square `v`.
+fn jacobi<T: Float>(a: &Matrix<T>) -> (Matrix<T>, Vec<T>, Matrix<T>) {
+    let n = a.cols;
+    let mut u = a.clone();
+    let mut v = Matrix::identity(n);
+    let two = T::one() + T::one();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for k in 0..u.rows {
+                    alpha += u[(k, p)] * u[(k, p)];
+                    beta += u[(k, q)] * u[(k, q)];
+                    gamma += u[(k, p)] * u[(k, q)];
+                }
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() || gamma == T::zero() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = T::one() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                let t = if zeta < T::zero() { -t } else { t };
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                rotate_columns(&mut u, p, q, c, s);
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            (0..u.rows)
+                .fold(T::zero(), |acc, k| acc + u[(k, j)] * u[(k, j)])
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let s: Vec<T> = order.iter().map(|&i| norms[i]).collect();
+    let mut u = permute_columns(&u, &order);
+    let v = permute_columns(&v, &order);
+
+    // Columns for negligible singular values carry no direction; they are
+    // rebuilt by `complete_basis`.
+    let tol = s.first().copied().unwrap_or(T::zero()) * T::epsilon() * T::from_usize(u.rows);
+    let mut valid = vec![false; n];
+    for (j, &sigma) in s.iter().enumerate() {
+        if sigma > tol {
+            valid[j] = true;
+            for k in 0..u.rows {
+                u[(k, j)] /= sigma;
+            }
+        }
+    }
+    (complete_basis(&u, &valid, n), s, v)
+}
+
+/// Extends the `valid` orthonormal columns of `m` to `cols` orthonormal
+/// columns, filling invalid and new columns with Gram-Schmidt
+/// orthogonalised unit vectors.
+fn complete_basis<T: Float>(m: &Matrix<T>, valid: &[bool], cols: usize) -> Matrix<T> {
+    let rows = m.rows;
+    let mut out = Matrix::zeros(rows, cols);
+    let mut basis: Vec<usize> = Vec::new();
+    for (j, _) in valid.iter().enumerate().filter(|&(_, &ok)| ok) {
+        for k in 0..rows {
+            out[(k, j)] = m[(k, j)];
+        }
+        basis.push(j);
+    }
+
+    for j in (0..cols).filter(|&j| !valid.get(j).copied().unwrap_or(false)) {
+        let mut best: Option<(T, Vec<T>)> = None;
+        for e in 0..rows {
+            let mut x = vec![T::zero(); rows];
+            x[e] = T::one();
+            // Orthogonalise twice for numerical stability.
+            for _ in 0..2 {
+                for &b in &basis {
+                    let dot = (0..rows).fold(T::zero(), |acc, k| acc + out[(k, b)] * x[k]);
+                    for (k, xk) in x.iter_mut().enumerate() {
+                        *xk -= dot * out[(k, b)];
+                    }
+                }
+            }
+            let norm = x.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt();
+            if best.as_ref().is_none_or(|(n, _)| norm > *n) {
+                best = Some((norm, x));
+            }
+        }
+        if let Some((norm, x)) = best {
+            for (k, xk) in x.into_iter().enumerate() {
+                out[(k, j)] = xk / norm;
+            }
+        }
+        basis.push(j);
+    }
+    out
+}
+
+/// SVD of a single matrix; `full` makes `u` and `v` square.
+pub(crate) fn factor<T: Float>(a: &Matrix<T>, full: bool) -> Factors<T> {
+    let (m, n) = (a.rows, a.cols);
+    let k = m.min(n);
+    if m >= n {
+        let (u, s, v) = jacobi(a);
+        let cols = if full { m } else { k };
+        let valid = vec![true; n];
+        Factors {
+            u: complete_basis(&u, &valid, cols),
+            s,
+            v,
+        }
+    } else {
+        let (v, s, u) = jacobi(&a.transpose());
+        let cols = if full { n } else { k };
+        let valid = vec![true; m];
+        Factors {
+            u,
+            s,
+            v: complete_basis(&v, &valid, cols),
+        }
+    }
+}
+
+/// Computes the singular value decomposition of each matrix in `a` using
+/// one-sided Jacobi rotations. With `full_matrices`, `u` and `vt` are square.
+pub fn svd<T: Float>(a: &Tensor<T>, full_matrices: bool) -> Result<Svd<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let rank = a.shape.len();
+    let (m, n) = (a.shape[rank - 2], a.shape[rank - 1]);
+    let k = m.min(n);
+    let (u_cols, vt_rows) = if full_matrices { (m, n) } else { (k, k) };
+
+    let (mut us, mut ss, mut vts) = (Vec::new(), AlignedVec::new(), Vec::new());
+    for matrix in &matrices {
+        let f = factor(matrix, full_matrices);
+        us.push(f.u.block(m, u_cols));
+        ss.extend(f.s);
+        vts.push(f.v.transpose().block(vt_rows, n));
+    }
+
+    let mut s_shape = batch.clone();
+    s_shape.push(k);
+    Ok(Svd {
+        u: rebatch(&batch, m, u_cols, us),
+        s: batch_tensor(&s_shape, ss),
+        vt: rebatch(&batch, vt_rows, n, vts),
+    })
+}
+
+/// Singular values of each matrix in `a`, descending, as `[..., k]`.
+pub(crate) fn singular_values<T: Float>(a: &Tensor<T>) -> Result<(Vec<usize>, Vec<Vec<T>>)> {
+    let (batch, matrices) = unbatch(a)?;
+    let values = matrices.iter().map(|m| factor(m, false).s).collect();
+    Ok((batch, values))
+}
+
+/// Default cutoff below which singular values count as zero.
+fn default_tol<T: Float>(s: &[T], m: usize, n: usize) -> T {
+    s.first().copied().unwrap_or(T::zero()) * T::from_usize(m.max(n)) * T::epsilon()
+}
+
+/// Number of singular values of each matrix above `tol`, which defaults to
+/// `σ_max · max(m, n) · ε`.
+pub fn matrix_rank<T: Float>(a: &Tensor<T>, tol: Option<T>) -> Result<Tensor<usize>> {
+    let (batch, values) = singular_values(a)?;
+    let rank = a.shape.len();
+    let (m, n) = (a.shape[rank - 2], a.shape[rank - 1]);
+    let ranks = values
+        .iter()
+        .map(|s| {
+            let tol = tol.unwrap_or_else(|| default_tol(s, m, n));
+            s.iter().filter(|&&sigma| sigma > tol).count()
+        })
+        .collect();
+    Ok(batch_tensor(&batch, ranks))
+}
+
+/// 2-norm condition number `σ_max / σ_min` of each matrix; infinite for
+/// singular matrices.
+pub fn cond<T: Float>(a: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, values) = singular_values(a)?;
+    let conds = values
+        .iter()
+        .map(|s| match (s.first(), s.last()) {
+            (Some(&max), Some(&min)) if min > T::zero() => max / min,
+            (Some(_), Some(_)) => T::infinity(),
+            _ => T::zero(),
+        })
+        .collect();
+    Ok(batch_tensor(&batch, conds))
+}
+
+/// Moore-Penrose pseudo-inverse of each matrix in `a`, computed from its SVD;
+/// an `m x n` input gives an `n x m` result. Singular values at or below
+/// `σ_max · max(m, n) · ε` are treated as zero.
+pub fn pinv<T: Float>(a: &Tensor<T>) -> Result<Tensor<T>> {
+    let (batch, matrices) = unbatch(a)?;
+    let rank = a.shape.len();
+    let (m, n) = (a.shape[rank - 2], a.shape[rank - 1]);
+
+    let inverses = matrices
+        .iter()
+        .map(|matrix| {
+            let f = factor(matrix, false);
+            let tol = default_tol(&f.s, m, n);
+            // v · diag(1 / s) · uᵀ, skipping the negligible singular values.
+            let mut out = Matrix::zeros(n, m);
+            for (j, &sigma) in f.s.iter().enumerate() {
+                if sigma <= tol {
+                    continue;
+                }
+                for r in 0..n {
+                    let scaled = f.v[(r, j)] / sigma;
+                    for c in 0..m {
+                        out[(r, c)] += scaled * f.u[(c, j)];
+                    }
+                }
+            }
+            out
+        })
+        .collect();
+    Ok(rebatch(&batch, n, m, inverses))
+}
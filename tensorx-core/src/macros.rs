@@ -0,0 +1,50 @@
+/// Creates a [`Tensor`](crate::tensor::Tensor) of any rank from a nested
+/// bracket literal.
+///
+/// Each level is built as a fixed-size array, so ragged input is rejected at
+/// compile time.
+///
+/// ```
+/// use tensorx_core::tensor;
+///
+/// let t = tensor![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(t.get_shape(), &[2, 3]);
+/// ```
+#[macro_export]
+macro_rules! tensor {
+    ($($elem:expr),+ $(,)?) => {
+        $crate::tensor::Tensor::from([$($elem),+])
+    };
+}
+
+/// Asserts that two float tensors have the same shape and are elementwise
+/// close, i.e. `|actual - expected| <= atol + rtol * |expected|`. On failure
+/// the panic message names the first mismatching multi-index and the values
+/// there.
+///
+/// Tolerances default to `rtol = 1e-5` and `atol = 1e-8`; NaNs compare equal
+/// to each other.
+///
+/// ```
+/// use tensorx_core::{assert_tensor_close, tensor};
+///
+/// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// assert_tensor_close!(a, tensor![[1.0, 2.0], [3.0, 4.000001]]);
+/// assert_tensor_close!(a, tensor![[1.1, 2.0], [3.0, 4.0]], rtol = 0.0, atol = 0.2);
+/// ```
+#[macro_export]
+macro_rules! assert_tensor_close {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assert_tensor_close!($actual, $expected, rtol = 1e-5, atol = 1e-8)
+    };
+    ($actual:expr, $expected:expr, rtol = $rtol:expr, atol = $atol:expr $(,)?) => {
+        match $actual.first_mismatch(&$expected, $rtol, $atol, true) {
+            Ok(None) => {}
+            Ok(Some((index, actual, expected))) => panic!(
+                "tensors differ at index {:?}: {:?} != {:?} (rtol = {:?}, atol = {:?})",
+                index, actual, expected, $rtol, $atol
+            ),
+            Err(err) => panic!("tensors cannot be compared: {}", err),
+        }
+    };
+}
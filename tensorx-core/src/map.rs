@@ -0,0 +1,173 @@
+//! Generic elementwise combinators. All of them visit elements in logical
+//! order, so they work on permuted and offset tensors, and always return
+//! contiguous results.
+
+use crate::broadcast::broadcast_shapes;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Positions, Tensor, compute_strides};
+use crate::view::TensorView;
+
+fn contiguous_from<T>(shape: Vec<usize>, data: AlignedVec<T>) -> Tensor<T> {
+    Tensor {
+        data,
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Returns a new tensor with `f` applied to every element.
+    pub fn map<U, F>(&self, mut f: F) -> Tensor<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let data = self.positions().map(|i| f(&self.data[i])).collect();
+        contiguous_from(self.shape.clone(), data)
+    }
+
+    /// Applies `f` to every element in place.
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for i in self.positions() {
+            f(&mut self.data[i]);
+        }
+    }
+
+    /// Combines two tensors elementwise after broadcasting them to a common
+    /// shape.
+    pub fn zip_with<U, V, F>(&self, rhs: &Tensor<U>, mut f: F) -> Result<Tensor<V>>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        let shape = broadcast_shapes(&self.shape, &rhs.shape)?;
+        let data = self
+            .broadcast_positions(&shape)?
+            .zip(rhs.broadcast_positions(&shape)?)
+            .map(|(a, b)| f(&self.data[a], &rhs.data[b]))
+            .collect();
+        Ok(contiguous_from(shape, data))
+    }
+
+    /// Updates `self` in place from `rhs`, which must broadcast to `self`'s
+    /// shape.
+    pub fn zip_mut_with<U, F>(&mut self, rhs: &Tensor<U>, mut f: F) -> Result<()>
+    where
+        F: FnMut(&mut T, &U),
+    {
+        let positions = self.positions().zip(rhs.broadcast_positions(&self.shape)?);
+        for (a, b) in positions {
+            f(&mut self.data[a], &rhs.data[b]);
+        }
+        Ok(())
+    }
+
+    /// Combines three tensors elementwise after broadcasting them to a common
+    /// shape.
+    pub fn zip3_with<U, V, W, F>(&self, b: &Tensor<U>, c: &Tensor<V>, mut f: F) -> Result<Tensor<W>>
+    where
+        F: FnMut(&T, &U, &V) -> W,
+    {
+        let shape = broadcast_shapes(&broadcast_shapes(&self.shape, &b.shape)?, &c.shape)?;
+        let data = self
+            .broadcast_positions(&shape)?
+            .zip(b.broadcast_positions(&shape)?)
+            .zip(c.broadcast_positions(&shape)?)
+            .map(|((i, j), k)| f(&self.data[i], &b.data[j], &c.data[k]))
+            .collect();
+        Ok(contiguous_from(shape, data))
+    }
+
+    /// Folds every lane along `axis` into one value, starting from `init`.
+    /// The result has `axis` removed from its shape.
+    pub fn fold_axis<U, F>(&self, axis: usize, init: U, mut f: F) -> Result<Tensor<U>>
+    where
+        U: Clone,
+        F: FnMut(U, &T) -> U,
+    {
+        let rank = self.shape.len();
+        if axis >= rank {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+
+        let data = self
+            .lanes(axis)?
+            .map(|lane| lane.iter().fold(init.clone(), &mut f))
+            .collect();
+
+        let mut shape = self.shape.clone();
+        shape.remove(axis);
+        Ok(contiguous_from(shape, data))
+    }
+
+    /// Replaces every lane along `axis` with the `len` values returned by
+    /// `f`. The result has the same shape as `self` except that `axis` has
+    /// length `len`.
+    pub fn map_lanes<U, F>(&self, axis: usize, len: usize, mut f: F) -> Result<Tensor<U>>
+    where
+        F: FnMut(TensorView<'_, T>) -> Vec<U>,
+    {
+        let rank = self.shape.len();
+        if axis >= rank {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+
+        let mut shape = self.shape.clone();
+        shape[axis] = len;
+        let strides = compute_strides(&shape);
+        let mut outer_shape = shape.clone();
+        let mut outer_strides = strides.clone();
+        outer_shape.remove(axis);
+        outer_strides.remove(axis);
+
+        let mut slots: Vec<Option<U>> = (0..shape.iter().product()).map(|_| None).collect();
+        let starts = Positions::new(&outer_shape, &outer_strides, 0);
+        for (lane, start) in self.lanes(axis)?.zip(starts) {
+            let values = f(lane);
+            if values.len() != len {
+                return Err(TensorError::ShapeMismatch {
+                    expected: vec![len],
+                    actual: vec![values.len()],
+                });
+            }
+            for (j, value) in values.into_iter().enumerate() {
+                slots[start + j * strides[axis]] = Some(value);
+            }
+        }
+
+        // Every slot is filled unless some other axis has length zero, in
+        // which case there are no slots at all.
+        let data = slots.into_iter().flatten().collect();
+        Ok(contiguous_from(shape, data))
+    }
+
+    /// Inclusive prefix scan along `axis`: element `i` of every lane becomes
+    /// `x[0] op x[1] op ... op x[i]`. `op` should be associative, so that
+    /// the grouping of the combinations does not change the result.
+    pub fn scan<F>(&self, axis: usize, mut op: F) -> Result<Tensor<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> T,
+    {
+        let rank = self.shape.len();
+        let len = *self
+            .shape
+            .get(axis)
+            .ok_or(TensorError::InvalidAxis { axis, rank })?;
+
+        self.map_lanes(axis, len, |lane| {
+            let mut out: Vec<T> = Vec::with_capacity(len);
+            for x in lane.iter() {
+                let next = match out.last() {
+                    Some(acc) => op(acc, x),
+                    None => x.clone(),
+                };
+                out.push(next);
+            }
+            out
+        })
+    }
+}
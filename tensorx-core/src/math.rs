@@ -0,0 +1,224 @@
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_PI};
+
+use crate::element::Float;
+use crate::error::Result;
+use crate::special::erf;
+use crate::tensor::Tensor;
+
+// Scalar kernels shared with `LazyTensor`, so fused evaluation matches the
+// eager ops bit for bit.
+
+pub(crate) fn relu<T: Float>(x: T) -> T {
+    x.maximum(T::zero())
+}
+
+pub(crate) fn sigmoid<T: Float>(x: T) -> T {
+    T::one() / (T::one() + (-x).exp())
+}
+
+impl<T: Float> Tensor<T> {
+    pub fn relu(&self) -> Result<Self> {
+        Ok(self.map(|&x| relu(x)))
+    }
+
+    pub fn sigmoid(&self) -> Result<Self> {
+        Ok(self.map(|&x| sigmoid(x)))
+    }
+
+    pub fn tanh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.tanh()))
+    }
+
+    // pub fn softmax(&self, axis: usize) -> Self {
+    //     assert!(axis < self.shape.len(), "Invalid axis");
+    //     unimplemented!("softmax activation")
+    // }
+
+    pub fn exp(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.exp()))
+    }
+
+    pub fn log(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.ln()))
+    }
+
+    pub fn powf(&self, exponent: T) -> Result<Self> {
+        Ok(self.map(|&x| x.powf(exponent)))
+    }
+
+    pub fn sqrt(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.sqrt()))
+    }
+
+    pub fn sin(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.sin()))
+    }
+
+    pub fn cos(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.cos()))
+    }
+
+    pub fn tan(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.tan()))
+    }
+
+    pub fn asin(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.asin()))
+    }
+
+    pub fn acos(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.acos()))
+    }
+
+    pub fn atan(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.atan()))
+    }
+
+    pub fn sinh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.sinh()))
+    }
+
+    pub fn cosh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.cosh()))
+    }
+
+    pub fn asinh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.asinh()))
+    }
+
+    pub fn acosh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.acosh()))
+    }
+
+    pub fn atanh(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.atanh()))
+    }
+
+    pub fn abs(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.abs()))
+    }
+
+    pub fn floor(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.floor()))
+    }
+
+    pub fn ceil(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.ceil()))
+    }
+
+    /// Rounds to the nearest integer, with half-way cases away from zero.
+    pub fn round(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.round()))
+    }
+
+    pub fn trunc(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.trunc()))
+    }
+
+    /// `-1`, `0` or `1` by the sign of each element. Zeros keep their sign
+    /// bit and NaN stays NaN.
+    pub fn sign(&self) -> Result<Self> {
+        Ok(self.map(|&x| {
+            if x > T::zero() {
+                T::one()
+            } else if x < T::zero() {
+                -T::one()
+            } else {
+                x
+            }
+        }))
+    }
+
+    /// Limits every element to `[min, max]`; NaN stays NaN.
+    pub fn clamp(&self, min: T, max: T) -> Result<Self> {
+        Ok(self.map(|&x| x.maximum(min).minimum(max)))
+    }
+
+    /// `ln(1 + x)`, accurate for small `x`.
+    pub fn log1p(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.ln_1p()))
+    }
+
+    /// `e^x - 1`, accurate for small `x`.
+    pub fn expm1(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.exp_m1()))
+    }
+
+    pub fn log2(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.log2()))
+    }
+
+    pub fn log10(&self) -> Result<Self> {
+        Ok(self.map(|&x| x.log10()))
+    }
+
+    /// `1 / sqrt(x)`.
+    pub fn rsqrt(&self) -> Result<Self> {
+        Ok(self.map(|&x| T::one() / x.sqrt()))
+    }
+
+    /// `x` for non-negative inputs, `negative_slope * x` otherwise.
+    pub fn leaky_relu(&self, negative_slope: T) -> Result<Self> {
+        Ok(self.map(|&x| if x < T::zero() { negative_slope * x } else { x }))
+    }
+
+    /// `x` for positive inputs, `alpha * (e^x - 1)` otherwise.
+    pub fn elu(&self, alpha: T) -> Result<Self> {
+        Ok(self.map(|&x| if x > T::zero() { x } else { alpha * x.exp_m1() }))
+    }
+
+    /// Scaled ELU with the self-normalising constants of Klambauer et al.
+    pub fn selu(&self) -> Result<Self> {
+        let alpha = T::from_f64(1.673_263_242_354_377_3);
+        let scale = T::from_f64(1.050_700_987_355_480_5);
+        Ok(self.map(|&x| scale * if x > T::zero() { x } else { alpha * x.exp_m1() }))
+    }
+
+    /// Gaussian error linear unit, `x · Φ(x)`. With `approximate` the normal
+    /// CDF `Φ` is replaced by the tanh approximation of Hendrycks and Gimpel.
+    pub fn gelu(&self, approximate: bool) -> Result<Self> {
+        let half = T::from_f64(0.5);
+        if approximate {
+            let k = T::from_f64(FRAC_2_PI.sqrt());
+            let c = T::from_f64(0.044_715);
+            Ok(self.map(|&x| half * x * (T::one() + (k * (x + c * x * x * x)).tanh())))
+        } else {
+            Ok(self.map(|&x| half * x * (T::one() + T::from_f64(erf(x.to_f64() * FRAC_1_SQRT_2)))))
+        }
+    }
+
+    /// `x · sigmoid(x)`, also known as swish with `beta = 1`.
+    pub fn silu(&self) -> Result<Self> {
+        self.swish(T::one())
+    }
+
+    /// `x · sigmoid(beta · x)`.
+    pub fn swish(&self, beta: T) -> Result<Self> {
+        Ok(self.map(|&x| x / (T::one() + (-beta * x).exp())))
+    }
+
+    /// `x · tanh(softplus(x))`.
+    pub fn mish(&self) -> Result<Self> {
+        Ok(self.map(|&x| x * softplus(x).tanh()))
+    }
+
+    /// `ln(1 + e^x)`, computed without overflow for large `x`.
+    pub fn softplus(&self) -> Result<Self> {
+        Ok(self.map(|&x| softplus(x)))
+    }
+
+    /// `x / (1 + |x|)`.
+    pub fn softsign(&self) -> Result<Self> {
+        Ok(self.map(|&x| x / (T::one() + x.abs())))
+    }
+
+    /// Clamps to `[min, max]`; the same as [`Tensor::clamp`].
+    pub fn hardtanh(&self, min: T, max: T) -> Result<Self> {
+        self.clamp(min, max)
+    }
+}
+
+/// `ln(1 + e^x)` rewritten as `max(x, 0) + ln(1 + e^-|x|)`.
+fn softplus<T: Float>(x: T) -> T {
+    x.maximum(T::zero()) + (-x.abs()).exp().ln_1p()
+}
@@ -0,0 +1,329 @@
+//! Tensor storage and memory accounting.
+//!
+//! Every [`Tensor`](crate::tensor::Tensor) keeps its elements in an
+//! [`AlignedVec`], whose buffer starts on an [`ALIGNMENT`]-byte boundary so
+//! SIMD kernels can use aligned loads. Buffers come from the process-wide
+//! [`TensorAllocator`] installed with [`set_allocator`], which defaults to the
+//! system allocator, and every allocation is counted in [`memory_stats`].
+
+use std::alloc::{Layout, handle_alloc_error};
+use std::fmt;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Alignment in bytes of every tensor buffer, one cache line.
+pub const ALIGNMENT: usize = 64;
+
+/// Source of the memory behind tensor storage, e.g. an arena or a caching
+/// pool.
+///
+/// # Safety
+///
+/// `allocate` must return `None` or a block of at least `layout.size()`
+/// bytes aligned to `layout.align()`, which stays valid until it is passed
+/// to `deallocate`. Layouts passed in always have a non-zero size.
+pub unsafe trait TensorAllocator: Send + Sync {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by `allocate` on this allocator with
+    /// the same `layout`, and not deallocated since.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+}
+
+/// The default allocator, backed by [`std::alloc`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemAllocator;
+
+// SAFETY: `std::alloc::alloc` honours the layout, and `dealloc` receives
+// what `alloc` returned.
+unsafe impl TensorAllocator for SystemAllocator {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        // SAFETY: the layout has a non-zero size.
+        NonNull::new(unsafe { std::alloc::alloc(layout) })
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: forwarded from the caller.
+        unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) }
+    }
+}
+
+static ALLOCATOR: RwLock<&'static dyn TensorAllocator> = RwLock::new(&SystemAllocator);
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Installs the allocator used for tensor buffers from now on and returns
+/// the previous one. Existing buffers are still freed by the allocator that
+/// created them.
+pub fn set_allocator(allocator: &'static dyn TensorAllocator) -> &'static dyn TensorAllocator {
+    let mut current = ALLOCATOR.write().unwrap_or_else(|e| e.into_inner());
+    mem::replace(&mut *current, allocator)
+}
+
+fn current_allocator() -> &'static dyn TensorAllocator {
+    *ALLOCATOR.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Process-wide counters for tensor buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// Bytes currently allocated.
+    pub live_bytes: usize,
+    /// Highest value `live_bytes` has reached since start-up or the last
+    /// [`reset_peak_bytes`].
+    pub peak_bytes: usize,
+    /// Number of buffers allocated since start-up.
+    pub allocations: usize,
+}
+
+pub fn memory_stats() -> MemoryStats {
+    MemoryStats {
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Restarts peak tracking from the current live bytes.
+pub fn reset_peak_bytes() {
+    PEAK_BYTES.store(LIVE_BYTES.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+fn allocate(allocator: &dyn TensorAllocator, layout: Layout) -> NonNull<u8> {
+    let ptr = allocator
+        .allocate(layout)
+        .unwrap_or_else(|| handle_alloc_error(layout));
+    debug_assert_eq!(ptr.as_ptr() as usize % layout.align(), 0);
+    let live = LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ptr
+}
+
+/// # Safety
+///
+/// Same contract as [`TensorAllocator::deallocate`].
+unsafe fn deallocate(allocator: &dyn TensorAllocator, ptr: NonNull<u8>, layout: Layout) {
+    LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    // SAFETY: forwarded from the caller.
+    unsafe { allocator.deallocate(ptr, layout) }
+}
+
+fn buffer_layout<T>(capacity: usize) -> Layout {
+    Layout::array::<T>(capacity)
+        .and_then(|layout| layout.align_to(ALIGNMENT))
+        .expect("capacity overflow")
+}
+
+/// A growable buffer like `Vec<T>` whose storage is aligned to
+/// [`ALIGNMENT`] bytes and comes from the installed [`TensorAllocator`].
+pub struct AlignedVec<T> {
+    ptr: NonNull<T>,
+    len: usize,
+    capacity: usize,
+    allocator: &'static dyn TensorAllocator,
+}
+
+// SAFETY: `AlignedVec` owns its elements like `Vec` does, and allocators are
+// `Send + Sync`.
+unsafe impl<T: Send> Send for AlignedVec<T> {}
+unsafe impl<T: Sync> Sync for AlignedVec<T> {}
+
+impl<T> AlignedVec<T> {
+    pub fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            len: 0,
+            // Zero-sized elements never need a buffer.
+            capacity: if mem::size_of::<T>() == 0 {
+                usize::MAX
+            } else {
+                0
+            },
+            allocator: current_allocator(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut v = Self::new();
+        v.reserve(capacity);
+        v
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Makes room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.capacity {
+            return;
+        }
+        let capacity = required.max(self.capacity * 2).max(4);
+        let ptr = allocate(self.allocator, buffer_layout::<T>(capacity)).cast::<T>();
+        if self.capacity > 0 {
+            // SAFETY: the old buffer holds `len` initialized elements and
+            // cannot overlap the new one; it is then freed with its layout.
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), ptr.as_ptr(), self.len);
+                deallocate(
+                    self.allocator,
+                    self.ptr.cast(),
+                    buffer_layout::<T>(self.capacity),
+                );
+            }
+        }
+        self.ptr = ptr;
+        self.capacity = capacity;
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.reserve(1);
+        }
+        // SAFETY: `len < capacity`, so the slot is inside the buffer.
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// Moves the elements into a plain `Vec`.
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        // SAFETY: the elements are moved out bit for bit and `len` is reset
+        // so `drop` only frees the buffer.
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.as_ptr(), vec.as_mut_ptr(), self.len);
+            vec.set_len(self.len);
+        }
+        self.len = 0;
+        vec
+    }
+}
+
+impl<T> Drop for AlignedVec<T> {
+    fn drop(&mut self) {
+        // SAFETY: the first `len` elements are initialized, and a non-empty
+        // buffer was allocated by `self.allocator` with this layout.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            if self.capacity > 0 && mem::size_of::<T>() > 0 {
+                deallocate(
+                    self.allocator,
+                    self.ptr.cast(),
+                    buffer_layout::<T>(self.capacity),
+                );
+            }
+        }
+    }
+}
+
+impl<T> Deref for AlignedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: `ptr` is aligned and non-null, and `len` elements are
+        // initialized.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for AlignedVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: as in `deref`, and `&mut self` guarantees exclusivity.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Default for AlignedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for AlignedVec<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for AlignedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for AlignedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> Extend<T> for AlignedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for AlignedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = Self::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<T> From<Vec<T>> for AlignedVec<T> {
+    /// Moves the elements into a new buffer in one copy; the `Vec`'s own
+    /// buffer cannot be reused since it is not aligned to [`ALIGNMENT`].
+    fn from(mut vec: Vec<T>) -> Self {
+        let mut v = Self::with_capacity(vec.len());
+        // SAFETY: `v` has room for `vec.len()` elements, which are moved bit
+        // for bit; `vec` then forgets them so only its buffer is freed.
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), v.ptr.as_ptr(), vec.len());
+            v.len = vec.len();
+            vec.set_len(0);
+        }
+        v
+    }
+}
+
+impl<T> IntoIterator for AlignedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AlignedVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut AlignedVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
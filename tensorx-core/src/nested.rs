@@ -0,0 +1,178 @@
+use crate::element::Element;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+
+/// Nested arrays or `Vec`s of scalars that can be flattened into a tensor.
+///
+/// Arrays are rectangular by construction; `Vec`s are checked at runtime and
+/// report [`TensorError::ShapeMismatch`] when ragged.
+pub trait NestedData<T> {
+    /// Appends the extent of this level, and of every level below it, to `shape`.
+    fn nested_shape(&self, shape: &mut Vec<usize>);
+
+    /// Appends the shape of an element-less value of this type: the length
+    /// of arrays and 0 for `Vec`s, so that empty input keeps its rank.
+    fn empty_shape(shape: &mut Vec<usize>)
+    where
+        Self: Sized;
+
+    /// Pushes the scalars in row-major order, checking each level against `shape`.
+    fn flatten_into(&self, shape: &[usize], data: &mut AlignedVec<T>) -> Result<()>;
+}
+
+/// Nested data that is rectangular by construction: scalars and arrays of
+/// them, which convert into a tensor infallibly.
+pub trait NestedArray<T>: NestedData<T> {}
+
+impl<T: Element> NestedArray<T> for T {}
+
+impl<T, E: NestedArray<T>, const N: usize> NestedArray<T> for [E; N] {}
+
+impl<T: Element> NestedData<T> for T {
+    fn nested_shape(&self, _shape: &mut Vec<usize>) {}
+
+    fn empty_shape(_shape: &mut Vec<usize>) {}
+
+    fn flatten_into(&self, shape: &[usize], data: &mut AlignedVec<T>) -> Result<()> {
+        if !shape.is_empty() {
+            return Err(TensorError::ShapeMismatch {
+                expected: shape.to_vec(),
+                actual: Vec::new(),
+            });
+        }
+        data.push(*self);
+        Ok(())
+    }
+}
+
+fn flatten_level<T, E: NestedData<T>>(
+    items: &[E],
+    shape: &[usize],
+    data: &mut AlignedVec<T>,
+) -> Result<()> {
+    match shape.split_first() {
+        Some((&len, rest)) if len == items.len() => {
+            for item in items {
+                item.flatten_into(rest, data)?;
+            }
+            Ok(())
+        }
+        _ => {
+            let mut actual = vec![items.len()];
+            if let Some(first) = items.first() {
+                first.nested_shape(&mut actual);
+            }
+            Err(TensorError::ShapeMismatch {
+                expected: shape.to_vec(),
+                actual,
+            })
+        }
+    }
+}
+
+impl<T, E: NestedData<T>, const N: usize> NestedData<T> for [E; N] {
+    fn nested_shape(&self, shape: &mut Vec<usize>) {
+        shape.push(N);
+        match self.first() {
+            Some(first) => first.nested_shape(shape),
+            None => E::empty_shape(shape),
+        }
+    }
+
+    fn empty_shape(shape: &mut Vec<usize>) {
+        shape.push(N);
+        E::empty_shape(shape);
+    }
+
+    fn flatten_into(&self, shape: &[usize], data: &mut AlignedVec<T>) -> Result<()> {
+        flatten_level(self, shape, data)
+    }
+}
+
+impl<T, E: NestedData<T>> NestedData<T> for Vec<E> {
+    fn nested_shape(&self, shape: &mut Vec<usize>) {
+        shape.push(self.len());
+        match self.first() {
+            Some(first) => first.nested_shape(shape),
+            None => E::empty_shape(shape),
+        }
+    }
+
+    fn empty_shape(shape: &mut Vec<usize>) {
+        shape.push(0);
+        E::empty_shape(shape);
+    }
+
+    fn flatten_into(&self, shape: &[usize], data: &mut AlignedVec<T>) -> Result<()> {
+        flatten_level(self, shape, data)
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Builds a 1-D tensor that takes ownership of `data`.
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let shape = vec![data.len()];
+        let strides = compute_strides(&shape);
+        Self {
+            data: data.into(),
+            shape,
+            strides,
+            offset: 0,
+        }
+    }
+
+    /// Builds a tensor of any rank from nested arrays and `Vec`s, e.g.
+    /// `[vec![1, 2], vec![3, 4]]`.
+    ///
+    /// Returns [`TensorError::ShapeMismatch`] if the input is ragged.
+    pub fn from_nested<N: NestedData<T>>(nested: &N) -> Result<Self> {
+        let mut shape = Vec::new();
+        nested.nested_shape(&mut shape);
+
+        let mut data = AlignedVec::with_capacity(shape.iter().product());
+        nested.flatten_into(&shape, &mut data)?;
+
+        let strides = compute_strides(&shape);
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+
+    /// Builds a tensor of any rank from nested `Vec`s, e.g. `Vec<Vec<Vec<f32>>>`.
+    ///
+    /// Returns [`TensorError::ShapeMismatch`] if the input is ragged.
+    pub fn from_nested_vec<E: NestedData<T>>(nested: Vec<E>) -> Result<Self> {
+        Self::from_nested(&nested)
+    }
+}
+
+/// Nested arrays are rectangular by construction; arrays of `Vec`s go
+/// through [`Tensor::from_nested`] instead.
+impl<T, E: NestedArray<T>, const N: usize> From<[E; N]> for Tensor<T> {
+    fn from(arr: [E; N]) -> Self {
+        Self::from_nested(&arr).expect("nested arrays are always rectangular")
+    }
+}
+
+impl<T> From<Vec<T>> for Tensor<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self::from_vec(data)
+    }
+}
+
+impl<T> FromIterator<T> for Tensor<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: AlignedVec<T> = iter.into_iter().collect();
+        let shape = vec![data.len()];
+        Self {
+            data,
+            strides: compute_strides(&shape),
+            shape,
+            offset: 0,
+        }
+    }
+}
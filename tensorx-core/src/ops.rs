@@ -0,0 +1,140 @@
+//! Operator overloads for [`Tensor`].
+//!
+//! Binary operators broadcast their operands like the `elementwise_*` methods
+//! and panic if the shapes are incompatible; use those methods for a fallible
+//! version. Compound assignment writes into the left operand's existing
+//! storage, so the right operand must broadcast to the left operand's shape.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::tensor::Tensor;
+
+macro_rules! impl_tensor_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $method:ident) => {
+        impl<T: Copy + $Op<Output = T>> $Op<&Tensor<T>> for &Tensor<T> {
+            type Output = Tensor<T>;
+
+            fn $op(self, rhs: &Tensor<T>) -> Tensor<T> {
+                self.$method(rhs).unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+
+        impl<T: Copy + $Op<Output = T>> $Op<Tensor<T>> for &Tensor<T> {
+            type Output = Tensor<T>;
+
+            fn $op(self, rhs: Tensor<T>) -> Tensor<T> {
+                self.$op(&rhs)
+            }
+        }
+
+        impl<T: Copy + $Op<Output = T>> $Op<&Tensor<T>> for Tensor<T> {
+            type Output = Tensor<T>;
+
+            fn $op(self, rhs: &Tensor<T>) -> Tensor<T> {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl<T: Copy + $Op<Output = T>> $Op for Tensor<T> {
+            type Output = Tensor<T>;
+
+            fn $op(self, rhs: Tensor<T>) -> Tensor<T> {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl<T: Copy + $Op<Output = T>> $OpAssign<&Tensor<T>> for Tensor<T> {
+            fn $op_assign(&mut self, rhs: &Tensor<T>) {
+                self.zip_mut_with(rhs, |a, b| *a = $Op::$op(*a, *b))
+                    .unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+
+        impl<T: Copy + $Op<Output = T>> $OpAssign for Tensor<T> {
+            fn $op_assign(&mut self, rhs: Tensor<T>) {
+                self.$op_assign(&rhs)
+            }
+        }
+    };
+}
+
+impl_tensor_op!(Add, add, AddAssign, add_assign, elementwise_add);
+impl_tensor_op!(Sub, sub, SubAssign, sub_assign, elementwise_sub);
+impl_tensor_op!(Mul, mul, MulAssign, mul_assign, elementwise_mul);
+impl_tensor_op!(Div, div, DivAssign, div_assign, elementwise_div);
+
+macro_rules! impl_scalar_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident; $($t:ty),*) => {
+        $(
+            impl $Op<$t> for &Tensor<$t> {
+                type Output = Tensor<$t>;
+
+                fn $op(self, rhs: $t) -> Tensor<$t> {
+                    self.map(|x| $Op::$op(*x, rhs))
+                }
+            }
+
+            impl $Op<$t> for Tensor<$t> {
+                type Output = Tensor<$t>;
+
+                fn $op(mut self, rhs: $t) -> Tensor<$t> {
+                    self.$op_assign(rhs);
+                    self
+                }
+            }
+
+            impl $Op<&Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn $op(self, rhs: &Tensor<$t>) -> Tensor<$t> {
+                    rhs.map(|x| $Op::$op(self, *x))
+                }
+            }
+
+            impl $Op<Tensor<$t>> for $t {
+                type Output = Tensor<$t>;
+
+                fn $op(self, mut rhs: Tensor<$t>) -> Tensor<$t> {
+                    rhs.map_inplace(|x| *x = $Op::$op(self, *x));
+                    rhs
+                }
+            }
+
+            impl $OpAssign<$t> for Tensor<$t> {
+                fn $op_assign(&mut self, rhs: $t) {
+                    self.map_inplace(|x| *x = $Op::$op(*x, rhs));
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_ops {
+    ($($t:ty),*) => {
+        impl_scalar_op!(Add, add, AddAssign, add_assign; $($t),*);
+        impl_scalar_op!(Sub, sub, SubAssign, sub_assign; $($t),*);
+        impl_scalar_op!(Mul, mul, MulAssign, mul_assign; $($t),*);
+        impl_scalar_op!(Div, div, DivAssign, div_assign; $($t),*);
+    };
+}
+
+impl_scalar_ops!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl<T: Copy + Neg<Output = T>> Neg for &Tensor<T> {
+    type Output = Tensor<T>;
+
+    fn neg(self) -> Tensor<T> {
+        self.map(|x| -*x)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for Tensor<T> {
+    type Output = Tensor<T>;
+
+    fn neg(mut self) -> Tensor<T> {
+        self.map_inplace(|x| *x = -*x);
+        self
+    }
+}
@@ -0,0 +1,113 @@
+//! Padding and cropping.
+
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+
+/// How [`Tensor::pad`] fills the positions it adds beyond either end of an
+/// axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+    /// A fixed value.
+    Constant(T),
+    /// Mirrors the axis without repeating the edge, so `[a, b, c]` padded by
+    /// two on each side becomes `[c, b, a, b, c, b, a]`.
+    Reflect,
+    /// Repeats the edge value.
+    Replicate,
+    /// Wraps around to the opposite end of the axis.
+    Circular,
+}
+
+impl<T> PadMode<T> {
+    /// Position in an axis of length `len` that the logical position `i`
+    /// reads from, or `None` for the constant fill.
+    fn source(&self, i: isize, len: isize) -> Option<usize> {
+        let i = match self {
+            _ if (0..len).contains(&i) => i,
+            PadMode::Constant(_) => return None,
+            PadMode::Replicate => i.clamp(0, len - 1),
+            PadMode::Circular => i.rem_euclid(len),
+            PadMode::Reflect if len == 1 => 0,
+            PadMode::Reflect => {
+                let period = 2 * (len - 1);
+                let i = i.rem_euclid(period);
+                if i < len { i } else { period - i }
+            }
+        };
+        Some(i as usize)
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Pads every axis by `(before, after)` elements, filled according to
+    /// `mode`. Negative amounts crop instead. Padding is always taken from
+    /// the original extent of the axis, and may be wider than the axis
+    /// itself for every mode.
+    pub fn pad(&self, pad: &[(isize, isize)], mode: PadMode<T>) -> Result<Self> {
+        let rank = self.shape.len();
+        if pad.len() != rank {
+            return Err(TensorError::DimensionalMismatch {
+                expected: rank,
+                actual: pad.len(),
+            });
+        }
+
+        // For every axis, the storage offset each output index reads from.
+        let mut shape = Vec::with_capacity(rank);
+        let mut sources = Vec::with_capacity(rank);
+        for (axis, (&(before, after), (&len, &stride))) in pad
+            .iter()
+            .zip(self.shape.iter().zip(&self.strides))
+            .enumerate()
+        {
+            let len = len as isize;
+            let padded = len + before + after;
+            if padded < 0 {
+                return Err(TensorError::InvalidArgument(format!(
+                    "padding ({before}, {after}) crops more than the {len} elements of axis {axis}"
+                )));
+            }
+            if len == 0 && padded > 0 && !matches!(mode, PadMode::Constant(_)) {
+                return Err(TensorError::InvalidArgument(format!(
+                    "axis {axis} is empty, so only constant padding can extend it"
+                )));
+            }
+            shape.push(padded as usize);
+            sources.push(
+                (0..padded)
+                    .map(|i| mode.source(i - before, len).map(|i| i * stride))
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        let numel = shape.iter().product();
+        let mut data = AlignedVec::with_capacity(numel);
+        let mut index = vec![0; rank];
+        for _ in 0..numel {
+            let position = index
+                .iter()
+                .zip(&sources)
+                .try_fold(self.offset, |acc, (&i, axis)| axis[i].map(|p| acc + p));
+            data.push(match (position, &mode) {
+                (Some(p), _) => self.data[p].clone(),
+                (None, PadMode::Constant(value)) => value.clone(),
+                (None, _) => unreachable!("only constant padding leaves gaps"),
+            });
+            for axis in (0..rank).rev() {
+                index[axis] += 1;
+                if index[axis] < shape[axis] {
+                    break;
+                }
+                index[axis] = 0;
+            }
+        }
+
+        Ok(Tensor {
+            data,
+            strides: compute_strides(&shape),
+            shape,
+            offset: 0,
+        })
+    }
+}
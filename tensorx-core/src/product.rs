@@ -0,0 +1,135 @@
+//! Vector and tensor products beyond [`Tensor::matmul`].
+
+use crate::element::Num;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides, matmul_kernel};
+
+fn check_axes(axes: &[usize], rank: usize) -> Result<()> {
+    for (i, &axis) in axes.iter().enumerate() {
+        if axis >= rank {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+        if axes[..i].contains(&axis) {
+            return Err(TensorError::InvalidPermutation {
+                dims: axes.to_vec(),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl<T: Num> Tensor<T> {
+    /// Inner product of two 1-D tensors of equal length.
+    pub fn dot(&self, rhs: &Self) -> Result<T> {
+        if let Some(t) = [self, rhs].into_iter().find(|t| t.shape.len() != 1) {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: t.shape.len(),
+            });
+        }
+        if self.shape != rhs.shape {
+            return Err(TensorError::DimensionalMismatch {
+                expected: self.shape[0],
+                actual: rhs.shape[0],
+            });
+        }
+        Ok(self
+            .iter()
+            .zip(rhs.iter())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+
+    /// Outer product `[m, n]` of the flattened inputs.
+    pub fn outer(&self, rhs: &Self) -> Result<Self> {
+        let data = self
+            .iter()
+            .flat_map(|&a| rhs.iter().map(move |&b| a * b))
+            .collect();
+        Tensor::from_aligned(vec![self.numel(), rhs.numel()], data)
+    }
+
+    /// Sums products over the last axis of both tensors; the result has shape
+    /// `self.shape[..-1] ++ rhs.shape[..-1]`.
+    pub fn inner(&self, rhs: &Self) -> Result<Self> {
+        if let Some(t) = [self, rhs].into_iter().find(|t| t.shape.is_empty()) {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: t.shape.len(),
+            });
+        }
+        self.tensordot(rhs, (&[self.shape.len() - 1], &[rhs.shape.len() - 1]))
+    }
+
+    /// Contracts `axes.0` of `self` with the matching `axes.1` of `rhs`. The
+    /// result holds the remaining axes of `self` followed by those of `rhs`.
+    pub fn tensordot(&self, rhs: &Self, axes: (&[usize], &[usize])) -> Result<Self> {
+        let (a_axes, b_axes) = axes;
+        if a_axes.len() != b_axes.len() {
+            return Err(TensorError::DimensionalMismatch {
+                expected: a_axes.len(),
+                actual: b_axes.len(),
+            });
+        }
+        check_axes(a_axes, self.shape.len())?;
+        check_axes(b_axes, rhs.shape.len())?;
+        for (&i, &j) in a_axes.iter().zip(b_axes) {
+            if self.shape[i] != rhs.shape[j] {
+                return Err(TensorError::DimensionalMismatch {
+                    expected: self.shape[i],
+                    actual: rhs.shape[j],
+                });
+            }
+        }
+
+        let a_free: Vec<usize> = (0..self.shape.len())
+            .filter(|i| !a_axes.contains(i))
+            .collect();
+        let b_free: Vec<usize> = (0..rhs.shape.len())
+            .filter(|i| !b_axes.contains(i))
+            .collect();
+        let m: usize = a_free.iter().map(|&i| self.shape[i]).product();
+        let k: usize = a_axes.iter().map(|&i| self.shape[i]).product();
+        let n: usize = b_free.iter().map(|&i| rhs.shape[i]).product();
+
+        let a = self.permute(&[&a_free[..], a_axes].concat())?.contiguous();
+        let b = rhs.permute(&[b_axes, &b_free[..]].concat())?.contiguous();
+        let data = matmul_kernel(1, m, k, n, &a.data, &b.data);
+
+        let shape = a_free
+            .iter()
+            .map(|&i| self.shape[i])
+            .chain(b_free.iter().map(|&i| rhs.shape[i]))
+            .collect();
+        Tensor::from_aligned(shape, data)
+    }
+
+    /// Kronecker product. The lower-rank operand is padded with leading axes
+    /// of length 1, and axis `i` of the result has length
+    /// `self.shape[i] * rhs.shape[i]`.
+    pub fn kron(&self, rhs: &Self) -> Result<Self> {
+        let rank = self.shape.len().max(rhs.shape.len());
+        let pad = |shape: &[usize]| {
+            let mut padded = vec![1; rank - shape.len()];
+            padded.extend_from_slice(shape);
+            padded
+        };
+        let (a_shape, b_shape) = (pad(&self.shape), pad(&rhs.shape));
+        let shape: Vec<usize> = a_shape.iter().zip(&b_shape).map(|(a, b)| a * b).collect();
+        let strides = compute_strides(&shape);
+
+        let a = self.reshape(a_shape)?;
+        let b = rhs.reshape(b_shape.clone())?;
+        let mut data: AlignedVec<T> =
+            std::iter::repeat_n(T::zero(), shape.iter().product()).collect();
+        for (ai, &x) in a.indexed_iter() {
+            for (bi, &y) in b.indexed_iter() {
+                let position = (0..rank)
+                    .map(|d| (ai[d] * b_shape[d] + bi[d]) * strides[d])
+                    .sum::<usize>();
+                data[position] = x * y;
+            }
+        }
+        Tensor::from_aligned(shape, data)
+    }
+}
@@ -0,0 +1,311 @@
+//! Affine integer quantization, `real = scale · (q - zero_point)`.
+//!
+//! Parameters are either shared by the whole tensor or given per index of
+//! one axis (per channel). Values round half away from zero and saturate at
+//! the bounds of the storage type; NaN has no quantized value and is
+//! rejected.
+
+use crate::element::{Int, Num};
+use crate::error::{Result, TensorError};
+use crate::stats::{Interpolation, quantile_of};
+use crate::tensor::{Tensor, compute_strides};
+
+/// Integer types a [`QuantizedTensor`] can store.
+pub trait Quantized: Int {
+    fn to_i32(self) -> i32;
+}
+
+impl Quantized for i8 {
+    fn to_i32(self) -> i32 {
+        self.into()
+    }
+}
+
+impl Quantized for u8 {
+    fn to_i32(self) -> i32 {
+        self.into()
+    }
+}
+
+/// Whether quantization parameters are shared or vary along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    #[default]
+    PerTensor,
+    /// One scale and zero point per index of the axis.
+    PerAxis(usize),
+}
+
+/// How [`QuantParams::calibrate`] picks the real range to represent.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Calibration {
+    /// The smallest and largest finite values.
+    #[default]
+    MinMax,
+    /// The `(100 - p)`-th to `p`-th percentile of the finite values, for `p`
+    /// in `[50, 100]`, so that outliers saturate instead of stretching the
+    /// scale.
+    Percentile(f64),
+}
+
+fn with_shape<T: Clone>(shape: Vec<usize>, data: &[T]) -> Tensor<T> {
+    Tensor {
+        data: data.iter().cloned().collect(),
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+/// Scales and zero points of a [`QuantizedTensor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantParams {
+    granularity: Granularity,
+    scale: Vec<f32>,
+    zero_point: Vec<i32>,
+}
+
+impl QuantParams {
+    pub fn per_tensor(scale: f32, zero_point: i32) -> Self {
+        Self {
+            granularity: Granularity::PerTensor,
+            scale: vec![scale],
+            zero_point: vec![zero_point],
+        }
+    }
+
+    pub fn per_axis(axis: usize, scale: Vec<f32>, zero_point: Vec<i32>) -> Result<Self> {
+        if scale.len() != zero_point.len() {
+            return Err(TensorError::DimensionalMismatch {
+                expected: scale.len(),
+                actual: zero_point.len(),
+            });
+        }
+        Ok(Self {
+            granularity: Granularity::PerAxis(axis),
+            scale,
+            zero_point,
+        })
+    }
+
+    /// Chooses parameters so the calibrated range of `t`, widened to include
+    /// zero, maps onto the full range of `Q`.
+    pub fn calibrate<Q: Quantized>(
+        t: &Tensor<f32>,
+        granularity: Granularity,
+        calibration: Calibration,
+    ) -> Result<Self> {
+        if let Calibration::Percentile(p) = calibration
+            && !(50.0..=100.0).contains(&p)
+        {
+            return Err(TensorError::InvalidArgument(format!(
+                "calibration percentile {p} is outside [50, 100]"
+            )));
+        }
+        let channels: Vec<Vec<f32>> = match granularity {
+            Granularity::PerTensor => vec![t.iter().copied().collect()],
+            Granularity::PerAxis(axis) => t
+                .axis_iter(axis)?
+                .map(|channel| channel.iter().copied().collect())
+                .collect(),
+        };
+
+        let (qmin, qmax) = (Q::min_value().to_f64(), Q::max_value().to_f64());
+        let (mut scale, mut zero_point) = (Vec::new(), Vec::new());
+        for mut values in channels {
+            values.retain(|x| x.is_finite());
+            let (lo, hi) = match calibration {
+                _ if values.is_empty() => (0.0, 0.0),
+                Calibration::MinMax => values
+                    .iter()
+                    .fold((f32::MAX, f32::MIN), |(lo, hi), &x| (lo.min(x), hi.max(x))),
+                Calibration::Percentile(p) => (
+                    quantile_of(values.clone(), 1.0 - p / 100.0, Interpolation::Linear),
+                    quantile_of(values, p / 100.0, Interpolation::Linear),
+                ),
+            };
+            let (lo, hi) = (f64::from(lo.min(0.0)), f64::from(hi.max(0.0)));
+            let s = ((hi - lo) / (qmax - qmin)) as f32;
+            let s = if s > 0.0 { s } else { 1.0 };
+            scale.push(s);
+            zero_point.push((qmin - lo / f64::from(s)).round().clamp(qmin, qmax) as i32);
+        }
+        Ok(Self {
+            granularity,
+            scale,
+            zero_point,
+        })
+    }
+
+    pub fn granularity(&self) -> Granularity {
+        self.granularity
+    }
+
+    pub fn scale(&self) -> &[f32] {
+        &self.scale
+    }
+
+    pub fn zero_point(&self) -> &[i32] {
+        &self.zero_point
+    }
+
+    /// Checks that the parameters fit a tensor of `shape` stored as `Q`.
+    fn check<Q: Quantized>(&self, shape: &[usize]) -> Result<()> {
+        if let Granularity::PerAxis(axis) = self.granularity {
+            let rank = shape.len();
+            let len = *shape
+                .get(axis)
+                .ok_or(TensorError::InvalidAxis { axis, rank })?;
+            if self.scale.len() != len {
+                return Err(TensorError::DimensionalMismatch {
+                    expected: len,
+                    actual: self.scale.len(),
+                });
+            }
+        }
+        if let Some(s) = self.scale.iter().find(|s| !(s.is_finite() && **s > 0.0)) {
+            return Err(TensorError::InvalidArgument(format!(
+                "quantization scale {s} is not positive and finite"
+            )));
+        }
+        let (qmin, qmax) = (Q::min_value().to_i32(), Q::max_value().to_i32());
+        if let Some(z) = self.zero_point.iter().find(|z| !(qmin..=qmax).contains(*z)) {
+            return Err(TensorError::InvalidArgument(format!(
+                "zero point {z} is outside [{qmin}, {qmax}]"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Scales and zero points as tensors that broadcast against a tensor of
+    /// rank `rank`.
+    fn broadcast(&self, rank: usize) -> (Tensor<f32>, Tensor<i32>) {
+        let shape = match self.granularity {
+            Granularity::PerTensor => vec![1],
+            Granularity::PerAxis(axis) => {
+                let mut shape = vec![1; rank - axis];
+                shape[0] = self.scale.len();
+                shape
+            }
+        };
+        (
+            with_shape(shape.clone(), &self.scale),
+            with_shape(shape, &self.zero_point),
+        )
+    }
+
+    /// Rounds `t / scale + zero_point` into `Q`, saturating.
+    fn quantize<T: Num, Q: Quantized>(&self, t: &Tensor<T>) -> Result<Tensor<Q>> {
+        self.check::<Q>(&t.shape)?;
+        if t.iter().any(|x| x.is_nan()) {
+            return Err(TensorError::InvalidArgument(
+                "cannot quantize NaN".to_string(),
+            ));
+        }
+        let (scale, zero_point) = self.broadcast(t.shape.len());
+        let (qmin, qmax) = (Q::min_value().to_f64(), Q::max_value().to_f64());
+        t.zip3_with(&scale, &zero_point, |&x, &s, &z| {
+            let q = (x.to_f64() / f64::from(s)).round() + f64::from(z);
+            Q::from_f64(q.clamp(qmin, qmax))
+        })
+    }
+}
+
+/// A tensor of quantized integers together with the parameters that map them
+/// back to real values.
+#[derive(Debug, Clone)]
+pub struct QuantizedTensor<Q> {
+    values: Tensor<Q>,
+    params: QuantParams,
+}
+
+impl<Q: Quantized> QuantizedTensor<Q> {
+    pub fn quantize(t: &Tensor<f32>, params: QuantParams) -> Result<Self> {
+        Ok(Self {
+            values: params.quantize(t)?,
+            params,
+        })
+    }
+
+    /// Wraps already quantized values.
+    pub fn from_parts(values: Tensor<Q>, params: QuantParams) -> Result<Self> {
+        params.check::<Q>(&values.shape)?;
+        Ok(Self { values, params })
+    }
+
+    pub fn dequantize(&self) -> Result<Tensor<f32>> {
+        let (scale, zero_point) = self.params.broadcast(self.values.shape.len());
+        self.values.zip3_with(&scale, &zero_point, |&q, &s, &z| {
+            s * (q.to_i32() - z) as f32
+        })
+    }
+
+    pub fn get_shape(&self) -> &[usize] {
+        &self.values.shape
+    }
+
+    pub fn values(&self) -> &Tensor<Q> {
+        &self.values
+    }
+
+    pub fn params(&self) -> &QuantParams {
+        &self.params
+    }
+
+    /// Multiplies two quantized matrices, accumulating the zero-point
+    /// corrected products in `i32` and requantizing the result with
+    /// `output`. The left operand may vary per row, the right operand and the
+    /// output per column.
+    ///
+    /// The inner dimension is limited so the accumulator cannot overflow,
+    /// e.g. to 33025 for 8-bit operands (`255 · 255 · k <= i32::MAX`).
+    pub fn matmul<R: Quantized>(
+        &self,
+        rhs: &QuantizedTensor<R>,
+        output: QuantParams,
+    ) -> Result<Self> {
+        let allowed = [
+            (self.params.granularity, 0, "left operand"),
+            (rhs.params.granularity, 1, "right operand"),
+            (output.granularity, 1, "output"),
+        ];
+        for (granularity, axis, operand) in allowed {
+            if granularity != Granularity::PerTensor && granularity != Granularity::PerAxis(axis) {
+                return Err(TensorError::InvalidArgument(format!(
+                    "the {operand} of a quantized matmul can only vary along axis {axis}"
+                )));
+            }
+        }
+
+        let k = self.values.shape.get(1).copied().unwrap_or(0);
+        let span = |min: i32, max: i32| i64::from(max) - i64::from(min);
+        let max_k = i64::from(i32::MAX)
+            / (span(Q::min_value().to_i32(), Q::max_value().to_i32())
+                * span(R::min_value().to_i32(), R::max_value().to_i32()));
+        if !i64::try_from(k).is_ok_and(|k| k <= max_k) {
+            return Err(TensorError::InvalidArgument(format!(
+                "inner dimension {k} of a quantized matmul exceeds {max_k}, \
+                 beyond which the i32 accumulator could overflow"
+            )));
+        }
+
+        let lhs = self.centered()?;
+        let rhs_centered = rhs.centered()?;
+        let acc = lhs.matmul(&rhs_centered)?;
+        let (lhs_scale, _) = self.params.broadcast(2);
+        let (rhs_scale, _) = rhs.params.broadcast(2);
+        let real = acc.zip3_with(&lhs_scale, &rhs_scale, |&acc, &a, &b| {
+            f64::from(acc) * f64::from(a) * f64::from(b)
+        })?;
+        Ok(Self {
+            values: output.quantize(&real)?,
+            params: output,
+        })
+    }
+
+    /// `q - zero_point` widened to `i32`.
+    fn centered(&self) -> Result<Tensor<i32>> {
+        let (_, zero_point) = self.params.broadcast(self.values.shape.len());
+        self.values.zip_with(&zero_point, |&q, &z| q.to_i32() - z)
+    }
+}
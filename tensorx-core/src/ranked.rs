@@ -0,0 +1,194 @@
+//! Tensors whose rank is part of the type.
+//!
+//! [`RankedTensor<T, R>`] wraps a dynamic [`Tensor`] whose rank is known to
+//! be `R`, so shapes and indices are `[usize; R]` and rank-specific
+//! operations only exist for the ranks they make sense for. Converting to
+//! and from [`Tensor`] moves the tensor without copying its data.
+//!
+//! ```compile_fail
+//! use tensorx_core::ranked::Tensor1;
+//!
+//! let v = Tensor1::new([3], 1.0);
+//! v.matmul(&v); // matmul is only defined for rank 2
+//! ```
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::display::DisplayElement;
+use crate::element::Num;
+use crate::error::{Result, TensorError};
+use crate::tensor::Tensor;
+
+/// A [`Tensor`] of rank `R`; see the module docs.
+#[derive(Debug, Clone)]
+pub struct RankedTensor<T, const R: usize> {
+    inner: Tensor<T>,
+}
+
+pub type Tensor0<T> = RankedTensor<T, 0>;
+pub type Tensor1<T> = RankedTensor<T, 1>;
+pub type Tensor2<T> = RankedTensor<T, 2>;
+pub type Tensor3<T> = RankedTensor<T, 3>;
+pub type Tensor4<T> = RankedTensor<T, 4>;
+pub type Tensor5<T> = RankedTensor<T, 5>;
+pub type Tensor6<T> = RankedTensor<T, 6>;
+
+impl<T, const R: usize> TryFrom<Tensor<T>> for RankedTensor<T, R> {
+    type Error = TensorError;
+
+    /// Fails with [`TensorError::RankMismatch`] unless `t` has rank `R`.
+    fn try_from(t: Tensor<T>) -> Result<Self> {
+        if t.shape.len() != R {
+            return Err(TensorError::RankMismatch {
+                expected: R,
+                actual: t.shape.len(),
+            });
+        }
+        Ok(Self { inner: t })
+    }
+}
+
+impl<T, const R: usize> From<RankedTensor<T, R>> for Tensor<T> {
+    fn from(t: RankedTensor<T, R>) -> Self {
+        t.inner
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Checks that the tensor has rank `R` and moves it into a
+    /// [`RankedTensor`].
+    pub fn into_ranked<const R: usize>(self) -> Result<RankedTensor<T, R>> {
+        RankedTensor::try_from(self)
+    }
+}
+
+impl<T, const R: usize> RankedTensor<T, R> {
+    pub fn shape(&self) -> [usize; R] {
+        let mut shape = [0; R];
+        shape.copy_from_slice(&self.inner.shape);
+        shape
+    }
+
+    pub fn as_dyn(&self) -> &Tensor<T> {
+        &self.inner
+    }
+
+    pub fn into_dyn(self) -> Tensor<T> {
+        self.inner
+    }
+
+    /// Applies `f` to every element; the result has the same rank.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> RankedTensor<U, R> {
+        RankedTensor {
+            inner: self.inner.map(f),
+        }
+    }
+
+    /// Combines two tensors of the same rank elementwise after broadcasting.
+    pub fn zip_with<U, V>(
+        &self,
+        rhs: &RankedTensor<U, R>,
+        f: impl FnMut(&T, &U) -> V,
+    ) -> Result<RankedTensor<V, R>> {
+        Ok(RankedTensor {
+            inner: self.inner.zip_with(&rhs.inner, f)?,
+        })
+    }
+}
+
+impl<T: Clone, const R: usize> RankedTensor<T, R> {
+    pub fn new(shape: [usize; R], fill: T) -> Self {
+        Self {
+            inner: Tensor::new(shape.to_vec(), fill),
+        }
+    }
+
+    pub fn from_data(shape: [usize; R], data: Vec<T>) -> Result<Self> {
+        Ok(Self {
+            inner: Tensor::from_data(shape.to_vec(), data)?,
+        })
+    }
+
+    pub fn get(&self, indices: [usize; R]) -> Result<&T> {
+        self.inner.get(&indices)
+    }
+
+    pub fn get_mut(&mut self, indices: [usize; R]) -> Result<&mut T> {
+        self.inner.get_mut(&indices)
+    }
+
+    pub fn set(&mut self, indices: [usize; R], new: T) -> Result<()> {
+        self.inner.set(&indices, new)
+    }
+
+    /// Returns a contiguous copy with the given shape, whose rank may differ.
+    pub fn reshape<const S: usize>(&self, shape: [usize; S]) -> Result<RankedTensor<T, S>> {
+        Ok(RankedTensor {
+            inner: self.inner.reshape(shape.to_vec())?,
+        })
+    }
+
+    pub fn permute(&self, dims: [usize; R]) -> Result<Self> {
+        Ok(Self {
+            inner: self.inner.permute(&dims)?,
+        })
+    }
+
+    pub fn transpose(&self, dim1: usize, dim2: usize) -> Result<Self> {
+        Ok(Self {
+            inner: self.inner.transpose(dim1, dim2)?,
+        })
+    }
+}
+
+impl<T: Clone, const R: usize> Index<[usize; R]> for RankedTensor<T, R> {
+    type Output = T;
+
+    /// Panics if `indices` is out of bounds.
+    fn index(&self, indices: [usize; R]) -> &T {
+        self.get(indices).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T: Clone, const R: usize> IndexMut<[usize; R]> for RankedTensor<T, R> {
+    fn index_mut(&mut self, indices: [usize; R]) -> &mut T {
+        self.get_mut(indices).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T: Clone> Tensor0<T> {
+    pub fn scalar(value: T) -> Self {
+        Self::new([], value)
+    }
+
+    pub fn into_scalar(self) -> T {
+        self[[]].clone()
+    }
+}
+
+impl<T: Num> Tensor1<T> {
+    pub fn dot(&self, rhs: &Self) -> Result<T> {
+        self.inner.dot(&rhs.inner)
+    }
+}
+
+impl<T: Num> Tensor2<T> {
+    pub fn matmul(&self, rhs: &Self) -> Result<Self> {
+        Ok(Self {
+            inner: self.inner.matmul(&rhs.inner)?,
+        })
+    }
+
+    /// Matrix-vector product.
+    pub fn matvec(&self, v: &Tensor1<T>) -> Result<Tensor1<T>> {
+        let [n] = v.shape();
+        self.matmul(&v.reshape([n, 1])?)?.reshape([self.shape()[0]])
+    }
+}
+
+impl<T: DisplayElement, const R: usize> fmt::Display for RankedTensor<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
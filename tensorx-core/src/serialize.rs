@@ -0,0 +1,63 @@
+//! Serde support for [`Tensor`].
+//!
+//! Tensors are written as `{"shape": [...], "data": [...]}` with the data in
+//! logical order, so views with arbitrary strides serialize the same way as
+//! their contiguous copies.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::{Result, TensorError};
+use crate::tensor::{Tensor, compute_strides};
+
+#[derive(Serialize)]
+struct TensorRef<'a, T> {
+    shape: &'a [usize],
+    data: Vec<&'a T>,
+}
+
+#[derive(Deserialize)]
+struct TensorRepr<T> {
+    shape: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<T: Serialize> Serialize for Tensor<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        TensorRef {
+            shape: &self.shape,
+            data: self.iter().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Tensor<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let TensorRepr { shape, data } = TensorRepr::deserialize(deserializer)?;
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(D::Error::custom(TensorError::ShapeMismatch {
+                expected: shape,
+                actual: vec![data.len()],
+            }));
+        }
+        Ok(Tensor {
+            data: data.into(),
+            strides: compute_strides(&shape),
+            shape,
+            offset: 0,
+        })
+    }
+}
+
+impl<T: Serialize> Tensor<T> {
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| TensorError::SerializationError(e.to_string()))
+    }
+}
+
+impl<T: for<'de> Deserialize<'de>> Tensor<T> {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| TensorError::DeserializationError(e.to_string()))
+    }
+}
@@ -0,0 +1,206 @@
+//! Sorting, selection and searching.
+//!
+//! Every routine orders values with NaN treated as larger than any other
+//! value, including infinity, and all NaNs as equal to each other. Ascending
+//! sorts therefore place NaNs last, descending sorts and [`Tensor::topk`]
+//! place them first, and [`Tensor::unique`] collapses them into one entry.
+
+use std::cmp::Ordering;
+
+use crate::element::Real;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+use crate::view::TensorView;
+
+/// Total order on `T` with NaN above every other value.
+pub(crate) fn compare<T: Real>(a: &T, b: &T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Distinct values of a tensor, as returned by [`Tensor::unique`].
+#[derive(Debug, Clone)]
+pub struct Unique<T> {
+    /// Sorted distinct values, as a 1-D tensor.
+    pub values: Tensor<T>,
+    /// How often each entry of `values` occurs.
+    pub counts: Tensor<usize>,
+    /// For every input element, the index of its value in `values`; has the
+    /// input's shape.
+    pub inverse: Tensor<usize>,
+}
+
+fn vector<T>(data: AlignedVec<T>) -> Tensor<T> {
+    let shape = vec![data.len()];
+    Tensor {
+        data,
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+/// Lane values paired with their positions in the lane.
+fn enumerate<T: Real>(lane: TensorView<'_, T>) -> Vec<(T, usize)> {
+    lane.iter().enumerate().map(|(i, &x)| (x, i)).collect()
+}
+
+fn split<T: Real>(pairs: Tensor<(T, usize)>) -> (Tensor<T>, Tensor<usize>) {
+    (pairs.map(|&(x, _)| x), pairs.map(|&(_, i)| i))
+}
+
+fn lane_len<T>(t: &Tensor<T>, axis: usize) -> Result<usize> {
+    let rank = t.shape.len();
+    t.shape
+        .get(axis)
+        .copied()
+        .ok_or(TensorError::InvalidAxis { axis, rank })
+}
+
+impl<T: Real> Tensor<T> {
+    /// Sorts every lane along `axis`, returning the sorted values and their
+    /// original positions within the lane. A `stable` sort keeps equal
+    /// values in their original order.
+    pub fn sort(
+        &self,
+        axis: usize,
+        descending: bool,
+        stable: bool,
+    ) -> Result<(Tensor<T>, Tensor<usize>)> {
+        let len = lane_len(self, axis)?;
+        let pairs = self.map_lanes(axis, len, |lane| {
+            let mut pairs = enumerate(lane);
+            let cmp = |a: &(T, usize), b: &(T, usize)| {
+                let order = compare(&a.0, &b.0);
+                if descending { order.reverse() } else { order }
+            };
+            if stable {
+                pairs.sort_by(cmp);
+            } else {
+                pairs.sort_unstable_by(cmp);
+            }
+            pairs
+        })?;
+        Ok(split(pairs))
+    }
+
+    /// Positions that would sort every lane along `axis`; see
+    /// [`Tensor::sort`].
+    pub fn argsort(&self, axis: usize, descending: bool, stable: bool) -> Result<Tensor<usize>> {
+        Ok(self.sort(axis, descending, stable)?.1)
+    }
+
+    /// The `k` largest values of every lane along `axis` in descending order,
+    /// with their positions. Uses a partial selection, so only the chosen
+    /// `k` values are fully sorted.
+    pub fn topk(&self, k: usize, axis: usize) -> Result<(Tensor<T>, Tensor<usize>)> {
+        let len = lane_len(self, axis)?;
+        if k > len {
+            return Err(TensorError::DimensionalMismatch {
+                expected: len,
+                actual: k,
+            });
+        }
+        let pairs = self.map_lanes(axis, k, |lane| {
+            let mut pairs = enumerate(lane);
+            let cmp = |a: &(T, usize), b: &(T, usize)| compare(&b.0, &a.0).then(a.1.cmp(&b.1));
+            if k > 0 && k < pairs.len() {
+                pairs.select_nth_unstable_by(k - 1, cmp);
+            }
+            pairs.truncate(k);
+            pairs.sort_unstable_by(cmp);
+            pairs
+        })?;
+        Ok(split(pairs))
+    }
+
+    /// The `k`-th smallest value of every lane along `axis`, counting from 1,
+    /// with its position. Like [`Tensor::sum`], the result keeps `axis` with
+    /// length 1.
+    pub fn kthvalue(&self, k: usize, axis: usize) -> Result<(Tensor<T>, Tensor<usize>)> {
+        let len = lane_len(self, axis)?;
+        if k == 0 || k > len {
+            return Err(TensorError::IndexOutOfBounds {
+                index: vec![k],
+                shape: vec![len],
+            });
+        }
+        let pairs = self.map_lanes(axis, 1, |lane| {
+            let mut pairs = enumerate(lane);
+            let (_, kth, _) =
+                pairs.select_nth_unstable_by(k - 1, |a, b| compare(&a.0, &b.0).then(a.1.cmp(&b.1)));
+            vec![*kth]
+        })?;
+        Ok(split(pairs))
+    }
+
+    /// The lower median of every lane along `axis`, i.e. the
+    /// `(n + 1) / 2`-th smallest value, with its position. No averaging is
+    /// done, so the result is always an element of the lane.
+    pub fn median(&self, axis: usize) -> Result<(Tensor<T>, Tensor<usize>)> {
+        let len = lane_len(self, axis)?;
+        self.kthvalue(len.div_ceil(2), axis)
+    }
+
+    /// The sorted distinct values of the flattened tensor, with their counts
+    /// and the index of every element's value.
+    pub fn unique(&self) -> Unique<T> {
+        let mut pairs: Vec<(T, usize)> = self.iter().copied().zip(0..).collect();
+        pairs.sort_by(|a, b| compare(&a.0, &b.0));
+
+        let mut values: AlignedVec<T> = AlignedVec::new();
+        let mut counts: AlignedVec<usize> = AlignedVec::new();
+        let mut inverse: AlignedVec<usize> = std::iter::repeat_n(0, pairs.len()).collect();
+        for (x, i) in pairs {
+            let last = values.len().wrapping_sub(1);
+            if values
+                .last()
+                .is_some_and(|v| compare(v, &x) == Ordering::Equal)
+            {
+                counts[last] += 1;
+            } else {
+                values.push(x);
+                counts.push(1);
+            }
+            inverse[i] = values.len() - 1;
+        }
+
+        Unique {
+            values: vector(values),
+            counts: vector(counts),
+            inverse: Tensor {
+                data: inverse,
+                strides: compute_strides(&self.shape),
+                shape: self.shape.clone(),
+                offset: 0,
+            },
+        }
+    }
+
+    /// For each of `values`, the index at which it would be inserted into
+    /// `self`, a 1-D tensor sorted in ascending order, to keep it sorted.
+    /// With `right` the index is past any equal entries, otherwise before
+    /// them. The result has the shape of `values`.
+    pub fn searchsorted(&self, values: &Tensor<T>, right: bool) -> Result<Tensor<usize>> {
+        let rank = self.shape.len();
+        if rank != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: rank,
+            });
+        }
+        let sorted: Vec<T> = self.iter().copied().collect();
+        Ok(values.map(|v| {
+            sorted.partition_point(|x| match compare(x, v) {
+                Ordering::Less => true,
+                Ordering::Equal => right,
+                Ordering::Greater => false,
+            })
+        }))
+    }
+}
@@ -0,0 +1,409 @@
+//! Sparse matrices in coordinate (COO) and compressed sparse row (CSR)
+//! layouts.
+//!
+//! Only stored entries take part in computation; every other entry is an
+//! implicit zero. COO entries may be unsorted and may repeat a position, in
+//! which case the duplicates add up, as they do when converting to dense or
+//! multiplying. [`SparseTensor::coalesce`] and conversion to CSR sort the
+//! entries row-major and merge duplicates.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+
+use crate::element::Num;
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::tensor::{Tensor, compute_strides};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseLayout {
+    /// Parallel row index, column index and value arrays.
+    Coo,
+    /// Column indices and values grouped by row, with `indptr[i]..indptr[i + 1]`
+    /// the range of row `i`.
+    Csr,
+}
+
+#[derive(Debug, Clone)]
+enum Indices {
+    Coo {
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+    },
+    Csr {
+        indptr: Vec<usize>,
+        cols: Vec<usize>,
+    },
+}
+
+/// A two-dimensional sparse matrix.
+#[derive(Debug, Clone)]
+pub struct SparseTensor<T> {
+    shape: [usize; 2],
+    indices: Indices,
+    values: Vec<T>,
+}
+
+fn check_bounds(shape: [usize; 2], row: usize, col: usize) -> Result<()> {
+    if row >= shape[0] || col >= shape[1] {
+        return Err(TensorError::IndexOutOfBounds {
+            index: vec![row, col],
+            shape: shape.to_vec(),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_len(expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(TensorError::DimensionalMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+impl<T> SparseTensor<T> {
+    /// Builds a COO matrix from parallel arrays of positions and values.
+    pub fn from_coo(
+        shape: [usize; 2],
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        ensure_len(values.len(), rows.len())?;
+        ensure_len(values.len(), cols.len())?;
+        for (&row, &col) in rows.iter().zip(&cols) {
+            check_bounds(shape, row, col)?;
+        }
+        Ok(Self {
+            shape,
+            indices: Indices::Coo { rows, cols },
+            values,
+        })
+    }
+
+    /// Builds a CSR matrix from its row pointer, column index and value
+    /// arrays.
+    pub fn from_csr(
+        shape: [usize; 2],
+        indptr: Vec<usize>,
+        cols: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        ensure_len(shape[0] + 1, indptr.len())?;
+        ensure_len(values.len(), cols.len())?;
+        let monotonic = indptr.windows(2).all(|w| w[0] <= w[1]);
+        if indptr[0] != 0 || indptr[shape[0]] != values.len() || !monotonic {
+            return Err(TensorError::InvalidArgument(format!(
+                "indptr {indptr:?} does not describe {} entries",
+                values.len()
+            )));
+        }
+        for row in 0..shape[0] {
+            for &col in &cols[indptr[row]..indptr[row + 1]] {
+                check_bounds(shape, row, col)?;
+            }
+        }
+        Ok(Self {
+            shape,
+            indices: Indices::Csr { indptr, cols },
+            values,
+        })
+    }
+
+    pub fn get_shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn layout(&self) -> SparseLayout {
+        match self.indices {
+            Indices::Coo { .. } => SparseLayout::Coo,
+            Indices::Csr { .. } => SparseLayout::Csr,
+        }
+    }
+
+    /// Number of stored entries, counting duplicates and explicit zeros.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Stored values in storage order.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Stored entries as `(row, col, value)` in storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        let positions: Box<dyn Iterator<Item = (usize, usize)> + '_> = match &self.indices {
+            Indices::Coo { rows, cols } => Box::new(rows.iter().copied().zip(cols.iter().copied())),
+            Indices::Csr { indptr, cols } => Box::new(
+                indptr
+                    .windows(2)
+                    .enumerate()
+                    .flat_map(move |(row, w)| cols[w[0]..w[1]].iter().map(move |&c| (row, c))),
+            ),
+        };
+        positions
+            .zip(&self.values)
+            .map(|((row, col), value)| (row, col, value))
+    }
+
+    /// Applies `f` to every stored value. Implicit zeros are left alone, so
+    /// `f` should map zero to zero for the result to be meaningful.
+    pub fn map_values<U, F>(&self, f: F) -> SparseTensor<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        SparseTensor {
+            shape: self.shape,
+            indices: self.indices.clone(),
+            values: self.values.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Num> SparseTensor<T> {
+    /// Builds a matrix in `layout` from sorted, duplicate-free entries, or
+    /// sorts and merges them first if `coalesce` is set.
+    fn from_entries(
+        shape: [usize; 2],
+        layout: SparseLayout,
+        mut entries: Vec<(usize, usize, T)>,
+        coalesce: bool,
+    ) -> Self {
+        if coalesce {
+            entries.sort_by_key(|&(row, col, _)| (row, col));
+            let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(entries.len());
+            for (row, col, value) in entries {
+                match merged.last_mut() {
+                    Some(last) if (last.0, last.1) == (row, col) => last.2 += value,
+                    _ => merged.push((row, col, value)),
+                }
+            }
+            entries = merged;
+        }
+
+        let values = entries.iter().map(|e| e.2).collect();
+        let cols = entries.iter().map(|e| e.1).collect();
+        let indices = match layout {
+            SparseLayout::Coo => Indices::Coo {
+                rows: entries.iter().map(|e| e.0).collect(),
+                cols,
+            },
+            SparseLayout::Csr => {
+                let mut indptr = vec![0; shape[0] + 1];
+                for &(row, _, _) in &entries {
+                    indptr[row + 1] += 1;
+                }
+                for i in 0..shape[0] {
+                    indptr[i + 1] += indptr[i];
+                }
+                Indices::Csr { indptr, cols }
+            }
+        };
+        Self {
+            shape,
+            indices,
+            values,
+        }
+    }
+
+    fn entries(&self) -> Vec<(usize, usize, T)> {
+        self.iter().map(|(row, col, &v)| (row, col, v)).collect()
+    }
+
+    /// Stores the non-zero elements of a 2-D tensor.
+    pub fn from_dense(dense: &Tensor<T>, layout: SparseLayout) -> Result<Self> {
+        let rank = dense.shape.len();
+        if rank != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: rank,
+            });
+        }
+        let entries = dense
+            .indexed_iter()
+            .filter(|&(_, &x)| x != T::zero())
+            .map(|(index, &x)| (index[0], index[1], x))
+            .collect();
+        let shape = [dense.shape[0], dense.shape[1]];
+        Ok(Self::from_entries(shape, layout, entries, false))
+    }
+
+    pub fn to_dense(&self) -> Tensor<T> {
+        let shape = self.shape.to_vec();
+        let mut data: AlignedVec<T> =
+            std::iter::repeat_n(T::zero(), self.shape[0] * self.shape[1]).collect();
+        for (row, col, &value) in self.iter() {
+            data[row * self.shape[1] + col] += value;
+        }
+        Tensor {
+            data,
+            strides: compute_strides(&shape),
+            shape,
+            offset: 0,
+        }
+    }
+
+    /// Sorts the entries row-major and sums duplicates, keeping the layout.
+    /// Entries that sum to zero stay stored.
+    pub fn coalesce(&self) -> Self {
+        Self::from_entries(self.shape, self.layout(), self.entries(), true)
+    }
+
+    /// Converts to COO, keeping the entries in storage order.
+    pub fn to_coo(&self) -> Self {
+        Self::from_entries(self.shape, SparseLayout::Coo, self.entries(), false)
+    }
+
+    /// Converts to CSR, coalescing the entries.
+    pub fn to_csr(&self) -> Self {
+        Self::from_entries(self.shape, SparseLayout::Csr, self.entries(), true)
+    }
+
+    /// The transposed matrix, in the same layout.
+    pub fn transpose(&self) -> Self {
+        let entries = self.iter().map(|(row, col, &v)| (col, row, v)).collect();
+        let shape = [self.shape[1], self.shape[0]];
+        let csr = self.layout() == SparseLayout::Csr;
+        Self::from_entries(shape, self.layout(), entries, csr)
+    }
+
+    /// Sparse-dense matrix product `self · dense`, for a `[k, n]` dense
+    /// operand and a dense `[m, n]` result.
+    pub fn spmm(&self, dense: &Tensor<T>) -> Result<Tensor<T>> {
+        let rank = dense.shape.len();
+        if rank != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: rank,
+            });
+        }
+        ensure_len(self.shape[1], dense.shape[0])?;
+
+        let n = dense.shape[1];
+        let dense = dense.contiguous();
+        let mut data: AlignedVec<T> = std::iter::repeat_n(T::zero(), self.shape[0] * n).collect();
+        for (row, col, &value) in self.iter() {
+            let out = &mut data[row * n..(row + 1) * n];
+            for (o, &d) in out.iter_mut().zip(&dense.data[col * n..(col + 1) * n]) {
+                *o += value * d;
+            }
+        }
+        Tensor::from_aligned(vec![self.shape[0], n], data)
+    }
+
+    /// Sparse matrix-vector product `self · x` for a 1-D `x`.
+    pub fn spmv(&self, x: &Tensor<T>) -> Result<Tensor<T>> {
+        let rank = x.shape.len();
+        if rank != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: rank,
+            });
+        }
+        ensure_len(self.shape[1], x.shape[0])?;
+
+        let x: Vec<T> = x.iter().copied().collect();
+        let mut data: AlignedVec<T> = std::iter::repeat_n(T::zero(), self.shape[0]).collect();
+        for (row, col, &value) in self.iter() {
+            data[row] += value * x[col];
+        }
+        Tensor::from_aligned(vec![self.shape[0]], data)
+    }
+
+    /// Merges the coalesced entries of both operands. With `union`, positions
+    /// stored in only one operand pair with zero; otherwise they are dropped.
+    fn merge<F>(&self, other: &Self, union: bool, mut f: F) -> Result<Self>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        if self.shape != other.shape {
+            return Err(TensorError::ShapeMismatch {
+                expected: self.shape.to_vec(),
+                actual: other.shape.to_vec(),
+            });
+        }
+        let a = self.coalesce().entries();
+        let b = other.coalesce().entries();
+        let (mut i, mut j) = (0, 0);
+        let mut entries = Vec::with_capacity(a.len() + b.len());
+        while i < a.len() || j < b.len() {
+            let order = match (a.get(i), b.get(j)) {
+                (Some(x), Some(y)) => (x.0, x.1).cmp(&(y.0, y.1)),
+                (Some(_), None) => Ordering::Less,
+                (None, _) => Ordering::Greater,
+            };
+            match order {
+                Ordering::Equal => {
+                    entries.push((a[i].0, a[i].1, f(a[i].2, b[j].2)));
+                    i += 1;
+                    j += 1;
+                }
+                Ordering::Less => {
+                    if union {
+                        entries.push((a[i].0, a[i].1, f(a[i].2, T::zero())));
+                    }
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    if union {
+                        entries.push((b[j].0, b[j].1, f(T::zero(), b[j].2)));
+                    }
+                    j += 1;
+                }
+            }
+        }
+        Ok(Self::from_entries(
+            self.shape,
+            self.layout(),
+            entries,
+            false,
+        ))
+    }
+
+    /// Elementwise sum, storing the union of both sparsity patterns.
+    pub fn elementwise_add(&self, other: &Self) -> Result<Self> {
+        self.merge(other, true, |a, b| a + b)
+    }
+
+    /// Elementwise difference, storing the union of both sparsity patterns.
+    pub fn elementwise_sub(&self, other: &Self) -> Result<Self> {
+        self.merge(other, true, |a, b| a - b)
+    }
+
+    /// Elementwise product, storing only positions present in both operands.
+    pub fn elementwise_mul(&self, other: &Self) -> Result<Self> {
+        self.merge(other, false, |a, b| a * b)
+    }
+
+    /// Elementwise product with a dense tensor that broadcasts to this
+    /// shape. The result keeps this matrix's sparsity pattern.
+    pub fn mul_dense(&self, dense: &Tensor<T>) -> Result<Self> {
+        let view = dense.broadcast_to(&self.shape)?;
+        let values = self
+            .iter()
+            .map(|(row, col, &v)| Ok(v * *view.get(&[row, col])?))
+            .collect::<Result<Vec<T>>>()?;
+        Ok(Self {
+            shape: self.shape,
+            indices: self.indices.clone(),
+            values,
+        })
+    }
+}
+
+macro_rules! impl_sparse_op {
+    ($Op:ident, $op:ident, $method:ident) => {
+        impl<T: Num> $Op for &SparseTensor<T> {
+            type Output = SparseTensor<T>;
+
+            fn $op(self, rhs: Self) -> SparseTensor<T> {
+                self.$method(rhs).unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+    };
+}
+
+impl_sparse_op!(Add, add, elementwise_add);
+impl_sparse_op!(Sub, sub, elementwise_sub);
+impl_sparse_op!(Mul, mul, elementwise_mul);
@@ -0,0 +1,350 @@
+//! Descriptive statistics.
+//!
+//! Reductions follow [`Tensor::sum`]: with `Some(axis)` the axis is kept with
+//! length 1, with `None` the whole tensor reduces to shape `[1]`. Means and
+//! variances use Welford's single-pass update, which avoids the cancellation
+//! of the textbook `E[x²] - E[x]²` formula.
+
+use crate::element::{Float, Int, Num};
+use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
+use crate::sort::compare;
+use crate::tensor::{Tensor, compute_strides, matmul_kernel};
+
+/// How [`Tensor::quantile`] picks a value when the requested quantile falls
+/// between two data points `lo <= hi`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// `lo + (hi - lo) * fraction`.
+    #[default]
+    Linear,
+    Lower,
+    Higher,
+    /// Whichever is closer, preferring the even index on a tie.
+    Nearest,
+    /// `(lo + hi) / 2`.
+    Midpoint,
+}
+
+/// Running count, mean and sum of squared deviations.
+struct Moments<T> {
+    count: usize,
+    mean: T,
+    m2: T,
+}
+
+fn welford<T: Float>(values: &[T]) -> Moments<T> {
+    let mut m = Moments {
+        count: 0,
+        mean: T::zero(),
+        m2: T::zero(),
+    };
+    for &x in values {
+        m.count += 1;
+        let delta = x - m.mean;
+        m.mean += delta / T::from_usize(m.count);
+        m.m2 += delta * (x - m.mean);
+    }
+    m
+}
+
+fn vector<T>(data: AlignedVec<T>) -> Tensor<T> {
+    let shape = vec![data.len()];
+    Tensor {
+        data,
+        strides: compute_strides(&shape),
+        shape,
+        offset: 0,
+    }
+}
+
+pub(crate) fn quantile_of<T: Float>(mut values: Vec<T>, q: f64, interpolation: Interpolation) -> T {
+    if values.is_empty() || values.iter().any(|x| x.is_nan()) {
+        return T::nan();
+    }
+    let position = q * (values.len() - 1) as f64;
+    let (lo, hi) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - lo as f64;
+
+    let (_, &mut lo_value, rest) = values.select_nth_unstable_by(lo, compare);
+    let hi_value = if hi == lo {
+        lo_value
+    } else {
+        rest.iter().copied().min_by(compare).unwrap_or(lo_value)
+    };
+
+    match interpolation {
+        _ if hi == lo => lo_value,
+        Interpolation::Linear => lo_value + (hi_value - lo_value) * T::from_f64(fraction),
+        Interpolation::Lower => lo_value,
+        Interpolation::Higher => hi_value,
+        Interpolation::Nearest => {
+            if fraction < 0.5 || (fraction == 0.5 && lo.is_multiple_of(2)) {
+                lo_value
+            } else {
+                hi_value
+            }
+        }
+        Interpolation::Midpoint => (lo_value + hi_value) / T::from_usize(2),
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    /// Applies `f` to every lane along `axis`, or to all elements.
+    fn reduce<F>(&self, axis: Option<usize>, mut f: F) -> Result<Self>
+    where
+        F: FnMut(Vec<T>) -> T,
+    {
+        match axis {
+            Some(axis) => self.map_lanes(axis, 1, |lane| vec![f(lane.iter().copied().collect())]),
+            None => Tensor::from_aligned(
+                vec![1],
+                std::iter::once(f(self.iter().copied().collect())).collect(),
+            ),
+        }
+    }
+
+    /// Arithmetic mean; NaN for an empty reduction.
+    pub fn mean(&self, axis: Option<usize>) -> Result<Self> {
+        self.reduce(axis, |values| {
+            let m = welford(&values);
+            if m.count == 0 { T::nan() } else { m.mean }
+        })
+    }
+
+    /// Variance with `ddof` delta degrees of freedom, dividing the sum of
+    /// squared deviations by `n - ddof`; NaN when `n <= ddof`.
+    pub fn var(&self, axis: Option<usize>, ddof: usize) -> Result<Self> {
+        self.reduce(axis, |values| {
+            let m = welford(&values);
+            if m.count <= ddof {
+                T::nan()
+            } else {
+                m.m2 / T::from_usize(m.count - ddof)
+            }
+        })
+    }
+
+    /// Standard deviation, the square root of [`Tensor::var`].
+    pub fn std(&self, axis: Option<usize>, ddof: usize) -> Result<Self> {
+        Ok(self.var(axis, ddof)?.map(|x| x.sqrt()))
+    }
+
+    /// The `q`-th quantile, for `q` in `[0, 1]`. Lanes containing NaN give
+    /// NaN.
+    pub fn quantile(
+        &self,
+        q: f64,
+        axis: Option<usize>,
+        interpolation: Interpolation,
+    ) -> Result<Self> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(TensorError::InvalidArgument(format!(
+                "quantile {q} is outside [0, 1]"
+            )));
+        }
+        self.reduce(axis, |values| quantile_of(values, q, interpolation))
+    }
+
+    /// The `p`-th percentile, for `p` in `[0, 100]`; see [`Tensor::quantile`].
+    pub fn percentile(
+        &self,
+        p: f64,
+        axis: Option<usize>,
+        interpolation: Interpolation,
+    ) -> Result<Self> {
+        self.quantile(p / 100.0, axis, interpolation)
+    }
+
+    /// Counts the elements falling into `bins` equal-width bins over `range`,
+    /// returning the counts and the `bins + 1` bin edges. Every bin is
+    /// half-open except the last, which includes its right edge. `range`
+    /// defaults to the minimum and maximum finite values; elements outside it
+    /// and NaNs are not counted.
+    pub fn histogram(
+        &self,
+        bins: usize,
+        range: Option<(T, T)>,
+    ) -> Result<(Tensor<usize>, Tensor<T>)> {
+        if bins == 0 {
+            return Err(TensorError::InvalidArgument(
+                "histogram needs at least one bin".to_string(),
+            ));
+        }
+        let (mut lo, mut hi) = match range {
+            Some(range) => range,
+            None => self
+                .iter()
+                .copied()
+                .filter(|x| x.is_finite())
+                .fold(None, |acc: Option<(T, T)>, x| match acc {
+                    Some((lo, hi)) => Some((lo.minimum(x), hi.maximum(x))),
+                    None => Some((x, x)),
+                })
+                .unwrap_or((T::zero(), T::one())),
+        };
+        if !(lo.is_finite() && hi.is_finite() && lo <= hi) {
+            return Err(TensorError::InvalidArgument(format!(
+                "invalid histogram range {lo:?}..{hi:?}"
+            )));
+        }
+        if lo == hi {
+            let half = T::from_f64(0.5);
+            (lo, hi) = (lo - half, hi + half);
+        }
+
+        let width = hi - lo;
+        let n = T::from_usize(bins);
+        let edges: AlignedVec<T> = (0..=bins)
+            .map(|i| lo + width * T::from_usize(i) / n)
+            .collect();
+        let mut counts: AlignedVec<usize> = std::iter::repeat_n(0, bins).collect();
+        for &x in self.iter() {
+            if x.is_nan() || x < lo || x > hi {
+                continue;
+            }
+            let mut bin = (((x - lo) / width * n).to_f64() as usize).min(bins - 1);
+            // Correct for rounding in the division so bins agree with `edges`.
+            if x < edges[bin] {
+                bin -= 1;
+            } else if bin + 1 < bins && x >= edges[bin + 1] {
+                bin += 1;
+            }
+            counts[bin] += 1;
+        }
+        Ok((vector(counts), vector(edges)))
+    }
+
+    /// Histogram with counts of the element type. If `min == max` the range
+    /// is taken from the data; see [`Tensor::histogram`].
+    pub fn histc(&self, bins: usize, min: T, max: T) -> Result<Self> {
+        let range = if min == max { None } else { Some((min, max)) };
+        Ok(self.histogram(bins, range)?.0.map(|&c| T::from_usize(c)))
+    }
+
+    /// Covariance matrix of the variables of a 2-D tensor whose `axis`
+    /// indexes observations; the other axis indexes variables. A 1-D tensor
+    /// is a single variable. The result is `[v, v]` for `v` variables, with
+    /// `ddof` as in [`Tensor::var`].
+    pub fn cov(&self, axis: usize, ddof: usize) -> Result<Self> {
+        let rank = self.shape.len();
+        if rank == 0 || rank > 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: rank,
+            });
+        }
+        if axis >= rank {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+
+        // Arrange as [variables, observations].
+        let x = match (rank, axis) {
+            (1, _) => self.reshape(vec![1, self.shape[0]])?,
+            (_, 0) => self.transpose(0, 1)?,
+            _ => self.clone(),
+        };
+        let (vars, obs) = (x.shape[0], x.shape[1]);
+        let centered = x.map_lanes(1, obs, |lane| {
+            let values: Vec<T> = lane.iter().copied().collect();
+            let mean = welford(&values).mean;
+            values.into_iter().map(|v| v - mean).collect()
+        })?;
+        let transposed = centered.transpose(0, 1)?.contiguous();
+
+        let mut products = matmul_kernel(1, vars, obs, vars, &centered.data, &transposed.data);
+        let scale = if obs <= ddof {
+            T::nan()
+        } else {
+            T::one() / T::from_usize(obs - ddof)
+        };
+        for p in products.iter_mut() {
+            *p *= scale;
+        }
+        Tensor::from_aligned(vec![vars, vars], products)
+    }
+
+    /// Pearson correlation coefficients of the variables, laid out as in
+    /// [`Tensor::cov`]. Values are clipped to `[-1, 1]`.
+    pub fn corrcoef(&self, axis: usize) -> Result<Self> {
+        let cov = self.cov(axis, 0)?;
+        let vars = cov.shape[0];
+        let stddev: Vec<T> = (0..vars).map(|i| cov.data[i * vars + i].sqrt()).collect();
+        let data = cov
+            .data
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| {
+                let r = c / (stddev[k / vars] * stddev[k % vars]);
+                if r.is_nan() {
+                    r
+                } else {
+                    r.maximum(-T::one()).minimum(T::one())
+                }
+            })
+            .collect();
+        Tensor::from_aligned(vec![vars, vars], data)
+    }
+}
+
+impl<T: Int> Tensor<T> {
+    /// Occurrences of each non-negative value in a 1-D tensor. The result has
+    /// length `max + 1`, or `minlength` if that is larger.
+    pub fn bincount(&self, minlength: usize) -> Result<Tensor<usize>> {
+        self.bin_weights(std::iter::repeat(1), minlength)
+    }
+
+    /// Like [`Tensor::bincount`], but each occurrence adds its entry of
+    /// `weights`, which must have the same shape as `self`.
+    pub fn bincount_weighted<W: Num>(
+        &self,
+        weights: &Tensor<W>,
+        minlength: usize,
+    ) -> Result<Tensor<W>> {
+        if weights.shape != self.shape {
+            return Err(TensorError::ShapeMismatch {
+                expected: self.shape.clone(),
+                actual: weights.shape.clone(),
+            });
+        }
+        self.bin_weights(weights.iter().copied(), minlength)
+    }
+
+    fn bin_weights<W: Num>(
+        &self,
+        weights: impl Iterator<Item = W>,
+        minlength: usize,
+    ) -> Result<Tensor<W>> {
+        let rank = self.shape.len();
+        if rank != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: rank,
+            });
+        }
+        let indices = self
+            .iter()
+            .map(|&x| {
+                x.to_usize().ok_or_else(|| {
+                    TensorError::InvalidArgument(if x < T::zero() {
+                        format!("bincount of negative value {x:?}")
+                    } else {
+                        format!("bincount value {x:?} does not fit in usize")
+                    })
+                })
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        let len = indices
+            .iter()
+            .map(|&i| i.saturating_add(1))
+            .max()
+            .unwrap_or(0)
+            .max(minlength);
+        let mut bins: AlignedVec<W> = std::iter::repeat_n(W::zero(), len).collect();
+        for (i, w) in indices.into_iter().zip(weights) {
+            bins[i] += w;
+        }
+        Ok(vector(bins))
+    }
+}
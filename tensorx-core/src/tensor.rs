@@ -1,28 +1,24 @@
-// use serde::Serialize;
-// use serde::de::DeserializeOwned;
-
+use crate::element::Num;
 use crate::error::{Result, TensorError};
+use crate::memory::AlignedVec;
 
 #[derive(Debug, Clone)]
 pub struct Tensor<T> {
-    data: Vec<T>,
-    shape: Vec<usize>,
-    strides: Vec<usize>,
-    offset: usize,
+    pub(crate) data: AlignedVec<T>,
+    pub(crate) shape: Vec<usize>,
+    pub(crate) strides: Vec<usize>,
+    pub(crate) offset: usize,
 }
 
-
-
 impl<T: Clone> Tensor<T> {
     pub fn get_offset(&self) -> &usize {
         &self.offset
     }
 
+    /// Fails unless every element is still inside the data with the new
+    /// offset.
     pub fn set_offset(&mut self, new: usize) -> Result<()> {
-        if self.data.len() < new {
-            return Err(TensorError::IndexOutOfBounds);
-        }
-
+        check_extent(self.data.len(), &self.shape, &self.strides, new)?;
         self.offset = new;
         Ok(())
     }
@@ -33,10 +29,13 @@ impl<T: Clone> Tensor<T> {
 
     pub fn set_data(&mut self, new: Vec<T>) -> Result<()> {
         if self.data.len() != new.len() {
-            return Err(TensorError::DimensionalMismatch);
+            return Err(TensorError::DimensionalMismatch {
+                expected: self.data.len(),
+                actual: new.len(),
+            });
         }
 
-        self.data = new;
+        self.data = new.into();
         Ok(())
     }
 
@@ -44,10 +43,16 @@ impl<T: Clone> Tensor<T> {
         &self.shape
     }
 
+    /// Replaces the shape, keeping the strides. Fails unless the rank is
+    /// unchanged and every element is still inside the data.
     pub fn set_shape(&mut self, new: Vec<usize>) -> Result<()> {
         if self.shape.len() != new.len() {
-            return Err(TensorError::ShapeMismatch);
+            return Err(TensorError::ShapeMismatch {
+                expected: self.shape.clone(),
+                actual: new,
+            });
         }
+        check_extent(self.data.len(), &new, &self.strides, self.offset)?;
 
         self.shape = new;
         Ok(())
@@ -57,62 +62,85 @@ impl<T: Clone> Tensor<T> {
         &self.strides
     }
 
+    /// Fails unless the rank is unchanged and every element is still inside
+    /// the data.
     pub fn set_strides(&mut self, new: Vec<usize>) -> Result<()> {
         if self.strides.len() != new.len() {
-            return Err(TensorError::DimensionalMismatch);
+            return Err(TensorError::RankMismatch {
+                expected: self.strides.len(),
+                actual: new.len(),
+            });
         }
+        check_extent(self.data.len(), &self.shape, &new, self.offset)?;
 
         self.strides = new;
         Ok(())
     }
 
+    fn out_of_bounds(&self, indices: &[usize]) -> TensorError {
+        TensorError::IndexOutOfBounds {
+            index: indices.to_vec(),
+            shape: self.shape.clone(),
+        }
+    }
+
+    /// Returns the position in the underlying data of the element at `indices`.
     pub fn get_index(&self, indices: &[usize]) -> Result<usize> {
-        if indices.len() != self.shape.len() {
-            return Err(TensorError::IndexOutOfBounds);
+        if indices.len() != self.shape.len() || indices.iter().zip(&self.shape).any(|(i, n)| i >= n)
+        {
+            return Err(self.out_of_bounds(indices));
         }
 
-        Ok(indices.iter().zip(&self.strides).map(|(i, s)| i * s).sum())
+        Ok(indices
+            .iter()
+            .zip(&self.strides)
+            .fold(self.offset, |acc, (i, s)| acc + i * s))
     }
 
     pub fn get(&self, indices: &[usize]) -> Result<&T> {
-        if let Ok(index) = self.get_index(indices) {
-            if let Some(val) = self.data.get(index) {
-                return Ok(val);
-            }
+        let index = self.get_index(indices)?;
+        match self.data.get(index) {
+            Some(val) => Ok(val),
+            None => Err(self.out_of_bounds(indices)),
         }
-        Err(TensorError::IndexOutOfBounds)
     }
 
     pub fn get_mut(&mut self, indices: &[usize]) -> Result<&mut T> {
-        if let Ok(index) = self.get_index(indices) {
-            if let Some(val) = self.data.get_mut(index) {
-                return Ok(val);
-            }
+        let index = self.get_index(indices)?;
+        if index >= self.data.len() {
+            return Err(self.out_of_bounds(indices));
         }
-        Err(TensorError::IndexOutOfBounds)
+        Ok(&mut self.data[index])
     }
 
     pub fn set(&mut self, indices: &[usize], new: T) -> Result<()> {
-        if let Ok(value) = self.get_mut(indices) {
-            *value = new;
-            return Ok(());
-        }
-        Err(TensorError::IndexOutOfBounds)
+        *self.get_mut(indices)? = new;
+        Ok(())
     }
 
-    pub fn reshape(&mut self, new_shape: Vec<usize>) -> Result<Self> {
-        if self.data.len() != new_shape.iter().product::<usize>() {
-            println!("Total size must remain the same");
-            return Err(TensorError::ShapeMismatch);
+    /// Returns a contiguous copy of the tensor with the given shape.
+    pub fn reshape(&self, new_shape: Vec<usize>) -> Result<Self> {
+        if self.numel() != new_shape.iter().product::<usize>() {
+            return Err(TensorError::ShapeMismatch {
+                expected: self.shape.clone(),
+                actual: new_shape,
+            });
         }
 
-        self.strides = compute_strides(&new_shape);
-        Ok(self.clone())
+        let mut result = self.contiguous();
+        result.strides = compute_strides(&new_shape);
+        result.shape = new_shape;
+        Ok(result)
+    }
+
+    /// Number of elements in the tensor.
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
     }
 
     pub fn new(shape: Vec<usize>, fill: T) -> Self {
         let total = shape.iter().product();
-        let data = vec![fill; total];
+        let data = std::iter::repeat_n(fill, total).collect();
         let strides = compute_strides(&shape);
 
         Self {
@@ -124,13 +152,17 @@ impl<T: Clone> Tensor<T> {
     }
 
     pub fn from_data(shape: Vec<usize>, data: Vec<T>) -> Result<Self> {
-        assert_eq!(
-            shape.iter().product::<usize>(),
-            data.len(),
-            "shape does not match data length"
-        );
+        Self::from_aligned(shape, data.into())
+    }
+
+    /// Like [`Tensor::from_data`], for buffers that are already tensor
+    /// storage.
+    pub(crate) fn from_aligned(shape: Vec<usize>, data: AlignedVec<T>) -> Result<Self> {
         if shape.iter().product::<usize>() != data.len() {
-            return Err(TensorError::ShapeMismatch);
+            return Err(TensorError::ShapeMismatch {
+                expected: shape,
+                actual: vec![data.len()],
+            });
         }
 
         let strides = compute_strides(&shape);
@@ -141,46 +173,124 @@ impl<T: Clone> Tensor<T> {
             offset: 0,
         })
     }
+}
 
-    pub fn from<const M: usize, const N: usize>(arr: [[T; N]; M]) -> Self {
-        let mut data = Vec::with_capacity(M * N);
-        for row in arr.iter() {
-            data.extend_from_slice(row);
-        }
-        let shape = vec![M, N];
-        let strides = compute_strides(&shape);
-        let offset = 0;
-        Self {
-            data,
-            shape,
-            strides,
-            offset,
-        }
-    }
+/// Checks that a layout only reaches positions below `len`, i.e. that
+/// `offset + Σ (shape[i] - 1) * strides[i] < len`. Empty layouts reach no
+/// position, but their offset may not pass the end of the data.
+fn check_extent(len: usize, shape: &[usize], strides: &[usize], offset: usize) -> Result<()> {
+    let (position, fits) = if shape.contains(&0) {
+        (Some(offset), offset <= len)
+    } else {
+        let last = shape.iter().zip(strides).try_fold(offset, |acc, (&n, &s)| {
+            acc.checked_add((n - 1).checked_mul(s)?)
+        });
+        (last, last.is_some_and(|last| last < len))
+    };
+    if fits {
+        return Ok(());
+    }
+    Err(TensorError::IndexOutOfBounds {
+        index: vec![position.unwrap_or(usize::MAX)],
+        shape: vec![len],
+    })
 }
 
 pub fn compute_strides(shape: &[usize]) -> Vec<usize> {
     let mut strides = vec![1; shape.len()];
-    for i in (0..shape.len() - 1).rev() {
+    for i in (0..shape.len().saturating_sub(1)).rev() {
         strides[i] = strides[i + 1] * shape[i + 1];
     }
     strides
 }
 
-impl<T: Copy> Tensor<T> {
-    pub fn assert_same_shape(&self, other: &Self) {
-        assert_eq!(self.shape, other.shape, "Shape mismatch");
+/// Walks the data positions of a strided layout in logical (row-major) order.
+#[derive(Debug, Clone)]
+pub(crate) struct Positions {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    index: Vec<usize>,
+    position: usize,
+    remaining: usize,
+}
+
+impl Positions {
+    pub(crate) fn new(shape: &[usize], strides: &[usize], offset: usize) -> Self {
+        Self {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            index: vec![0; shape.len()],
+            position: offset,
+            remaining: shape.iter().product(),
+        }
+    }
+
+    /// Like `next`, but also returns the multi-index of the position.
+    pub(crate) fn next_indexed(&mut self) -> Option<(Vec<usize>, usize)> {
+        let index = self.index.clone();
+        self.next().map(|position| (index, position))
+    }
+}
+
+impl Iterator for Positions {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let current = self.position;
+
+        for axis in (0..self.shape.len()).rev() {
+            self.index[axis] += 1;
+            self.position += self.strides[axis];
+            if self.index[axis] < self.shape[axis] {
+                break;
+            }
+            self.position -= self.strides[axis] * self.shape[axis];
+            self.index[axis] = 0;
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Positions {}
+
+impl<T> Tensor<T> {
+    pub(crate) fn positions(&self) -> Positions {
+        Positions::new(&self.shape, &self.strides, self.offset)
+    }
+
+    pub fn ensure_same_shape(&self, other: &Self) -> Result<()> {
+        if self.shape != other.shape {
+            return Err(TensorError::ShapeMismatch {
+                expected: self.shape.clone(),
+                actual: other.shape.clone(),
+            });
+        }
+        Ok(())
     }
 }
 
 impl<T: Clone> Tensor<T> {
     pub fn permute(&self, dims: &[usize]) -> Result<Self> {
         if dims.len() != self.get_shape().len() {
-            return Err(TensorError::ShapeMismatch);
+            return Err(TensorError::RankMismatch {
+                expected: self.get_shape().len(),
+                actual: dims.len(),
+            });
         }
 
         if !is_valid_perm(dims) {
-            return Err(TensorError::InvalidPermutation);
+            return Err(TensorError::InvalidPermutation {
+                dims: dims.to_vec(),
+            });
         }
 
         let new_shape = dims.iter().map(|&i| self.get_shape()[i]).collect();
@@ -195,7 +305,12 @@ impl<T: Clone> Tensor<T> {
     }
 
     pub fn transpose(&self, dim1: usize, dim2: usize) -> Result<Self> {
-        let mut dims: Vec<usize> = (0..self.shape.len()).collect();
+        let rank = self.shape.len();
+        if let Some(&axis) = [dim1, dim2].iter().find(|&&d| d >= rank) {
+            return Err(TensorError::InvalidAxis { axis, rank });
+        }
+
+        let mut dims: Vec<usize> = (0..rank).collect();
         dims.swap(dim1, dim2);
         self.permute(&dims)
     }
@@ -215,43 +330,24 @@ fn is_valid_perm(dims: &[usize]) -> bool {
 
 use std::ops::Sub;
 
-impl<T: Sub<Output = T> + Clone + Copy> Sub for Tensor<T> {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.elementwise_sub(&rhs)
-    }
-}
 impl<T> Tensor<T>
 where
     T: Copy + Sub<Output = T>,
 {
-    pub fn elementwise_sub(&self, rhs: &Self) -> Self {
-        self.assert_same_shape(rhs);
-        let data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| *a - *b)
-            .collect();
-        Self {
-            data,
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
-            offset: self.offset,
-        }
+    pub fn elementwise_sub(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| *a - *b)
     }
 }
 
 use std::ops::Mul;
 
-impl<T> Tensor<T>
-where
-    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
-{
+impl<T: Num> Tensor<T> {
     pub fn matmul(&self, rhs: &Self) -> Result<Self> {
-        if self.shape.len() != 2 || rhs.shape.len() != 2 {
-            return Err(TensorError::ShapeMismatch);
+        if let Some(t) = [self, rhs].into_iter().find(|t| t.shape.len() != 2) {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: t.shape.len(),
+            });
         }
 
         let m = self.shape[0];
@@ -260,197 +356,98 @@ where
         let n = rhs.shape[1];
 
         if k1 != k2 {
-            return Err(TensorError::DimensionalMismatch);
+            return Err(TensorError::DimensionalMismatch {
+                expected: k1,
+                actual: k2,
+            });
         }
 
-        let mut result = Tensor::new(vec![m, n], T::default());
+        let data = matmul_kernel(1, m, k1, n, &self.contiguous().data, &rhs.contiguous().data);
+        Tensor::from_aligned(vec![m, n], data)
+    }
+}
 
+/// Multiplies `batch` pairs of contiguous row-major matrices, `[m, k]` from
+/// `a` by `[k, n]` from `b`, returning the `[batch, m, n]` products.
+pub(crate) fn matmul_kernel<T: Num>(
+    batch: usize,
+    m: usize,
+    k: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+) -> AlignedVec<T> {
+    let mut out: AlignedVec<T> = std::iter::repeat_n(T::zero(), batch * m * n).collect();
+    for p in 0..batch {
+        let a = &a[p * m * k..(p + 1) * m * k];
+        let b = &b[p * k * n..(p + 1) * k * n];
+        let out = &mut out[p * m * n..(p + 1) * m * n];
         for i in 0..m {
-            for j in 0..n {
-                let mut sum = T::default();
-
-                for k in 0..k1 {
-                    let a = *self.get(&[i, k])?;
-                    let b = *rhs.get(&[k, j])?;
-                    sum = sum + (a * b);
+            for l in 0..k {
+                let x = a[i * k + l];
+                for (o, &y) in out[i * n..(i + 1) * n]
+                    .iter_mut()
+                    .zip(&b[l * n..(l + 1) * n])
+                {
+                    *o += x * y;
                 }
-                //let mut x = result.get_mut(&[i, j])?;
-                //x = &mut sum;
-                let _ = result.set(&[i, j], sum);
             }
         }
-
-        Ok(result)
     }
+    out
 }
 impl<T> Tensor<T>
 where
     T: Copy + Mul<Output = T>,
 {
-    pub fn elementwise_mul(&self, rhs: &Self) -> Self {
-        self.assert_same_shape(rhs);
-        let data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| *a * *b)
-            .collect();
-        Self {
-            data,
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
-            offset: self.offset,
-        }
-    }
-}
-
-impl<T: Mul<Output = T> + Clone + Copy> Mul for Tensor<T> {
-    type Output = Self;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.elementwise_mul(&rhs)
+    pub fn elementwise_mul(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| *a * *b)
     }
 }
 
 use std::ops::Div;
 
-impl<T: Div<Output = T> + Clone + Copy> Div for Tensor<T> {
-    type Output = Self;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        self.elementwise_div(&rhs)
-    }
-}
-
 impl<T> Tensor<T>
 where
     T: Copy + Div<Output = T>,
 {
-    pub fn elementwise_div(&self, rhs: &Self) -> Self {
-        self.assert_same_shape(rhs);
-        let data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| *a / *b)
-            .collect();
-        Self {
-            data,
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
-            offset: self.offset,
-        }
+    pub fn elementwise_div(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| *a / *b)
     }
 }
 
 use std::ops::Add;
 
-impl<T: Add<Output = T> + Clone + Copy> Add for Tensor<T> {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        self.elementwise_add(&rhs)
-    }
-}
-
 impl<T> Tensor<T>
 where
     T: Copy + Add<Output = T>,
 {
-    pub fn elementwise_add(&self, rhs: &Self) -> Self {
-        assert_eq!(self.shape, rhs.shape, "Shape mismatch for addition");
-        let data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| *a + *b)
-            .collect();
-
-        Tensor {
-            data,
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
-            offset: self.offset,
-        }
+    pub fn elementwise_add(&self, rhs: &Self) -> Result<Self> {
+        self.zip_with(rhs, |a, b| *a + *b)
     }
 }
 
-impl Tensor<f32> {
-    pub fn relu(&self) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.max(0.0)).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-    pub fn sigmoid(&self) -> Result<Self> {
-        let data = self
-            .get_data()
-            .iter()
-            .map(|x| 1.0 / (1.0 + (-x).exp()))
-            .collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-    pub fn tanh(&self) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.tanh()).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-
-    // pub fn softmax(&self, axis: usize) -> Self {
-    //     assert!(axis < self.shape.len(), "Invalid axis");
-    //     unimplemented!("softmax activation")
-    // }
-}
-
-impl Tensor<f32> {
-    pub fn exp(&self) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.exp()).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-
-    pub fn log(&self) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.ln()).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-
-    pub fn powf(&self, exponent: f32) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.powf(exponent)).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-
-    pub fn sqrt(&self) -> Result<Self> {
-        let data = self.get_data().iter().map(|x| x.sqrt()).collect();
-        Self::from_data(self.get_shape().to_vec(), data)
-    }
-}
-
-impl<T: Clone + Copy + Default + Add<Output = T>> Tensor<T> {
+impl<T: Num> Tensor<T> {
     pub fn sum(&self, axis: Option<usize>) -> Result<Self> {
         match axis {
             Some(axis) => {
-                if axis >= self.shape.len() {
-                    return Err(TensorError::InvalidAxis);
-                }
-
                 let mut new_shape = self.shape.clone();
-                new_shape[axis] = 1;
-
-                let result = Tensor::new(new_shape, T::default());
-
-                // Implement axis-wise summation
-                // ...
+                if let Some(len) = new_shape.get_mut(axis) {
+                    *len = 1;
+                }
 
+                let mut result = self.fold_axis(axis, T::zero(), |acc, &x| acc + x)?;
+                result.strides = compute_strides(&new_shape);
+                result.shape = new_shape;
                 Ok(result)
             }
             None => {
-                let sum = self.data.iter().fold(T::default(), |acc, &x| acc + x);
-                Tensor::from_data(vec![1], vec![sum])
+                let sum = self.iter().fold(T::zero(), |acc, &x| acc + x);
+                Tensor::from_aligned(vec![1], std::iter::once(sum).collect())
             }
         }
     }
 
-    // pub fn mean(&self, axis: Option<usize>) -> Result<Self> {
-    //     // Similar to sum but divide by number of elements
-    //     unimplemented!()
-    // }
-
     // pub fn max(&self, axis: Option<usize>) -> Result<Self> {
     //     // Find maximum values
     //     unimplemented!()
@@ -462,27 +459,23 @@ impl<T: Clone + Copy + Default + Add<Output = T>> Tensor<T> {
     // }
 }
 
-impl<T> Tensor<T> {
+impl<T: Clone> Tensor<T> {
+    /// Returns a copy whose data is laid out in row-major order with no offset.
     pub fn contiguous(&self) -> Self {
-        // Ensure tensor is contiguous in memory
-        // May require reordering elements
-        unimplemented!()
+        if self.is_contiguous() && self.offset == 0 && self.data.len() == self.numel() {
+            return self.clone();
+        }
+
+        let data = self.positions().map(|i| self.data[i].clone()).collect();
+        Self {
+            data,
+            shape: self.shape.clone(),
+            strides: compute_strides(&self.shape),
+            offset: 0,
+        }
     }
 
     pub fn is_contiguous(&self) -> bool {
-        // Check if tensor is contiguous
-        unimplemented!()
+        self.strides == compute_strides(&self.shape)
     }
 }
-
-// impl<T: Serialize> Tensor<T> {
-//     pub fn to_json(&self) -> Result<String> {
-//         serde_json::to_string(self).map_err(|_| TensorError::SerializationError)
-//     }
-// }
-
-// impl<T: DeserializeOwned> Tensor<T> {
-//     pub fn from_json(json: &str) -> Result<Self> {
-//         serde_json::from_str(json).map_err(|_| TensorError::DeserializationError)
-//     }
-// }
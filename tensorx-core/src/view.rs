@@ -3,17 +3,73 @@ use crate::tensor::Tensor;
 use std::ops::Range;
 
 pub struct TensorView<'data, T> {
-    data: &'data [T],
-    shape: Vec<usize>,
-    strides: Vec<usize>,
-    offset: usize,
+    pub(crate) data: &'data [T],
+    pub(crate) shape: Vec<usize>,
+    pub(crate) strides: Vec<usize>,
+    pub(crate) offset: usize,
 }
 
 pub struct TensorViewMut<'data, T> {
-    data: &'data mut [T],
-    shape: Vec<usize>,
-    strides: Vec<usize>,
-    //offset: usize,
+    pub(crate) data: &'data mut [T],
+    pub(crate) shape: Vec<usize>,
+    pub(crate) strides: Vec<usize>,
+    pub(crate) offset: usize,
+}
+
+fn flat_index(
+    shape: &[usize],
+    strides: &[usize],
+    offset: usize,
+    indices: &[usize],
+    len: usize,
+) -> Result<usize> {
+    let out_of_bounds = || TensorError::IndexOutOfBounds {
+        index: indices.to_vec(),
+        shape: shape.to_vec(),
+    };
+
+    if indices.len() != shape.len() {
+        return Err(out_of_bounds());
+    }
+    let mut flat_idx = offset;
+    for (i, &idx_i) in indices.iter().enumerate() {
+        if idx_i >= shape[i] {
+            return Err(out_of_bounds());
+        }
+        flat_idx += strides[i] * idx_i;
+    }
+    if flat_idx >= len {
+        return Err(out_of_bounds());
+    }
+    Ok(flat_idx)
+}
+
+/// Validates `range` along `axis` and returns the sliced shape and offset.
+fn slice_layout(
+    shape: &[usize],
+    strides: &[usize],
+    offset: usize,
+    axis: usize,
+    range: &Range<usize>,
+) -> Result<(Vec<usize>, usize)> {
+    if axis >= shape.len() {
+        return Err(TensorError::InvalidAxis {
+            axis,
+            rank: shape.len(),
+        });
+    }
+    if range.start > range.end || range.end > shape[axis] {
+        let mut index = vec![0; shape.len()];
+        index[axis] = range.end.max(range.start);
+        return Err(TensorError::IndexOutOfBounds {
+            index,
+            shape: shape.to_vec(),
+        });
+    }
+
+    let mut new_shape = shape.to_vec();
+    new_shape[axis] = range.end - range.start;
+    Ok((new_shape, offset + strides[axis] * range.start))
 }
 
 impl<'data, T> TensorViewMut<'data, T> {
@@ -22,42 +78,26 @@ impl<'data, T> TensorViewMut<'data, T> {
         axis: usize,
         range: Range<usize>,
     ) -> Result<TensorViewMut<'data, T>> {
-        if axis > self.shape.len() {
-            return Err(TensorError::IndexOutOfBounds);
-        }
-
-        let mut new_shape = self.shape.clone();
-        new_shape[axis] = range.end - range.start;
-
-        let new_offset = self.strides[axis] * range.start;
-
-        let start_i = new_offset;
-        let length = new_shape.iter().product::<usize>();
-        let new_data = &mut self.data[start_i..start_i + length];
+        let (new_shape, new_offset) =
+            slice_layout(&self.shape, &self.strides, self.offset, axis, &range)?;
 
         Ok(TensorViewMut {
-            data: new_data,
+            data: self.data,
             shape: new_shape,
             strides: self.strides.clone(),
-            //offset: 0
+            offset: new_offset,
         })
     }
 
     pub fn get_mut(&mut self, indices: &[usize]) -> Result<&mut T> {
-        if indices.len() != self.shape.len() {
-            return Err(TensorError::IndexOutOfBounds);
-        }
-        let mut flat_idx = 0;
-        for (i, &idx_i) in indices.iter().enumerate() {
-            if idx_i >= self.shape[i] {
-                return Err(TensorError::IndexOutOfBounds);
-            }
-            flat_idx += self.strides[i] * idx_i;
-        }
-        Ok(self
-            .data
-            .get_mut(flat_idx)
-            .ok_or(Err(TensorError::IndexOutOfBounds)?)?)
+        let flat_idx = flat_index(
+            &self.shape,
+            &self.strides,
+            self.offset,
+            indices,
+            self.data.len(),
+        )?;
+        Ok(&mut self.data[flat_idx])
     }
 
     pub fn data(&'data self) -> &'data [T] {
@@ -75,44 +115,42 @@ impl<'data, T> TensorViewMut<'data, T> {
     pub fn strides(&self) -> &[usize] {
         &self.strides
     }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
 }
 
-impl<T: Clone> Tensor<T> {
+impl<T> Tensor<T> {
     pub fn view(&'_ self) -> TensorView<'_, T> {
         TensorView {
-            data: &self.get_data(),
-            shape: (&self.get_shape()).to_vec(),
-            strides: self.get_strides().to_vec(),
-            offset: *self.get_offset(),
+            data: &self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
         }
     }
 
-    pub fn view_mut(&'_ mut self) -> TensorView<'_, T> {
-        TensorView {
-            data: &mut self.get_data(),
-            shape: self.get_shape().to_vec(),
-            strides: self.get_strides().to_vec(),
-            offset: *self.get_offset(),
+    pub fn view_mut(&'_ mut self) -> TensorViewMut<'_, T> {
+        TensorViewMut {
+            data: &mut self.data,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
         }
     }
 }
 
 impl<'data, T> TensorView<'data, T> {
     pub fn get(&self, indices: &[usize]) -> Result<&T> {
-        if indices.len() != self.shape.len() {
-            return Err(TensorError::IndexOutOfBounds);
-        }
-        let mut flat_idx = 0;
-        for (i, &idx_i) in indices.iter().enumerate() {
-            if idx_i >= self.shape[i] {
-                return Err(TensorError::IndexOutOfBounds);
-            }
-            flat_idx += self.strides[i] * idx_i;
-        }
-        Ok(self
-            .data
-            .get(flat_idx)
-            .ok_or(Err(TensorError::IndexOutOfBounds)?)?)
+        let flat_idx = flat_index(
+            &self.shape,
+            &self.strides,
+            self.offset,
+            indices,
+            self.data.len(),
+        )?;
+        Ok(&self.data[flat_idx])
     }
 
     pub fn get_data(&self) -> &'data [T] {
@@ -141,14 +179,8 @@ impl<'data, T> TensorView<'data, T> {
     }
 
     pub fn slice(&self, axis: usize, range: Range<usize>) -> Result<Self> {
-        if axis > self.shape.len() {
-            return Err(TensorError::IndexOutOfBounds);
-        }
-
-        let mut new_shape = self.shape.clone();
-        new_shape[axis] = range.end - range.start;
-
-        let new_offset = self.offset + self.strides[axis] * range.start;
+        let (new_shape, new_offset) =
+            slice_layout(&self.shape, &self.strides, self.offset, axis, &range)?;
 
         Ok(TensorView {
             data: self.data,
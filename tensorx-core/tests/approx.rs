@@ -0,0 +1,87 @@
+use tensorx_core::assert_tensor_close;
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+
+#[test]
+fn test_classification_masks() {
+    let t = tensor![1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    assert_eq!(t.isnan().get_data(), vec![false, true, false, false]);
+    assert_eq!(t.isinf().get_data(), vec![false, false, true, true]);
+    assert_eq!(t.isfinite().get_data(), vec![true, false, false, false]);
+
+    let cleaned = t.nan_to_num(0.0, f64::MAX, f64::MIN);
+    assert_eq!(cleaned.get_data(), vec![1.0, 0.0, f64::MAX, f64::MIN]);
+}
+
+#[test]
+fn test_isclose_and_allclose() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![1.0, 100.0, f64::NAN, f64::INFINITY];
+    let b = tensor![1.05, 100.5, f64::NAN, f64::INFINITY];
+    assert_eq!(
+        a.isclose(&b, 1e-2, 0.0, false)?.get_data(),
+        vec![false, true, false, true]
+    );
+    assert_eq!(
+        a.isclose(&b, 0.0, 0.1, true)?.get_data(),
+        vec![true, false, true, true]
+    );
+    assert!(a.allclose(&b, 0.0, 0.5, true)?);
+    assert!(!a.allclose(&b, 0.0, 0.5, false)?);
+
+    // Broadcasting against a scalar-like tensor.
+    let m = tensor![[1.0, 1.001], [0.999, 2.0]];
+    assert_eq!(
+        m.isclose(&tensor![1.0], 0.0, 0.01, false)?.get_data(),
+        vec![true, true, true, false]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_first_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[1.0f32, 2.0], [3.0, 4.0]];
+    let b = tensor![[1.0f32, 2.0], [3.5, 4.0]];
+    assert_eq!(
+        a.first_mismatch(&b, 1e-5, 1e-8, false)?,
+        Some((vec![1, 0], 3.0, 3.5))
+    );
+    assert_eq!(a.first_mismatch(&a, 0.0, 0.0, false)?, None);
+
+    // Strided tensors report logical indices.
+    let t = b.transpose(0, 1)?;
+    assert_eq!(
+        t.first_mismatch(&a.transpose(0, 1)?, 1e-5, 1e-8, false)?,
+        Some((vec![0, 1], 3.5, 3.0))
+    );
+
+    assert_eq!(
+        a.first_mismatch(&tensor![1.0f32, 2.0], 0.0, 0.0, false)
+            .unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![2],
+            actual: vec![2, 2]
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_assert_tensor_close_passes() {
+    let a = tensor![[0.1 + 0.2, 1.0], [f64::NAN, -2.0]];
+    assert_tensor_close!(a, tensor![[0.3, 1.0], [f64::NAN, -2.0]]);
+    assert_tensor_close!(
+        a,
+        tensor![[0.35, 1.0], [f64::NAN, -2.0]],
+        rtol = 0.0,
+        atol = 0.1
+    );
+}
+
+#[test]
+#[should_panic(expected = "tensors differ at index [1, 0]: 3.0 != 3.5")]
+fn test_assert_tensor_close_reports_index() {
+    assert_tensor_close!(
+        tensor![[1.0, 2.0], [3.0, 4.0]],
+        tensor![[1.0, 2.0], [3.5, 4.0]]
+    );
+}
@@ -0,0 +1,196 @@
+use tensorx_core::blas::{axpy, gemm, gemv, ger};
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor::Tensor;
+
+fn arange(shape: &[usize]) -> Tensor<f64> {
+    let n = shape.iter().product();
+    Tensor::from_data(shape.to_vec(), (0..n).map(|x| x as f64).collect()).unwrap()
+}
+
+#[test]
+fn test_dot_outer_inner() -> Result<(), Box<dyn std::error::Error>> {
+    let a = Tensor::from([1.0, 2.0, 3.0]);
+    let b = Tensor::from([4.0, 5.0, 6.0]);
+    assert_eq!(a.dot(&b)?, 32.0);
+
+    let outer = a.outer(&Tensor::from([1.0, -1.0]))?;
+    assert_eq!(outer.get_shape(), &[3, 2]);
+    assert_eq!(outer.get_data(), vec![1.0, -1.0, 2.0, -2.0, 3.0, -3.0]);
+
+    let inner = arange(&[2, 3]).inner(&arange(&[4, 3]))?;
+    assert_eq!(inner.get_shape(), &[2, 4]);
+    let expected = arange(&[2, 3]).matmul(&arange(&[4, 3]).transpose(0, 1)?)?;
+    assert_eq!(inner.get_data(), expected.get_data());
+
+    let scalar = a.inner(&b)?;
+    assert!(scalar.get_shape().is_empty());
+    assert_eq!(scalar.get_data(), vec![32.0]);
+    Ok(())
+}
+
+#[test]
+fn test_dot_errors() {
+    let a = Tensor::from([1.0, 2.0, 3.0]);
+    assert_eq!(
+        a.dot(&Tensor::from([1.0, 2.0])).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 3,
+            actual: 2
+        }
+    );
+    assert_eq!(
+        a.dot(&arange(&[3, 1])).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 1,
+            actual: 2
+        }
+    );
+}
+
+#[test]
+fn test_tensordot() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[3, 4, 5]);
+    let b = arange(&[4, 3, 2]);
+    let c = a.tensordot(&b, (&[1, 0], &[0, 1]))?;
+    assert_eq!(c.get_shape(), &[5, 2]);
+
+    let mut expected = vec![0.0; 10];
+    for (k, slot) in expected.iter_mut().enumerate() {
+        let (p, q) = (k / 2, k % 2);
+        for i in 0..3 {
+            for j in 0..4 {
+                *slot += a.get(&[i, j, p])? * b.get(&[j, i, q])?;
+            }
+        }
+    }
+    assert_eq!(c.get_data(), expected);
+
+    // No contracted axes gives the outer product.
+    let outer = arange(&[2]).tensordot(&arange(&[3]), (&[], &[]))?;
+    assert_eq!(outer.get_data(), vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0]);
+
+    assert_eq!(
+        a.tensordot(&b, (&[0], &[0])).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 3,
+            actual: 4
+        }
+    );
+    assert_eq!(
+        a.tensordot(&b, (&[3], &[0])).unwrap_err(),
+        TensorError::InvalidAxis { axis: 3, rank: 3 }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_kron() -> Result<(), Box<dyn std::error::Error>> {
+    let a = Tensor::from([[1.0, 2.0], [3.0, 4.0]]);
+    let b = Tensor::from([[0.0, 1.0], [1.0, 0.0]]);
+    let k = a.kron(&b)?;
+    assert_eq!(k.get_shape(), &[4, 4]);
+    assert_eq!(
+        k.get_data(),
+        vec![
+            0.0, 1.0, 0.0, 2.0, //
+            1.0, 0.0, 2.0, 0.0, //
+            0.0, 3.0, 0.0, 4.0, //
+            3.0, 0.0, 4.0, 0.0,
+        ]
+    );
+
+    // The lower-rank operand gains leading axes of length 1.
+    let k = Tensor::from([1.0, 10.0]).kron(&a)?;
+    assert_eq!(k.get_shape(), &[2, 4]);
+    assert_eq!(
+        k.get_data(),
+        vec![1.0, 2.0, 10.0, 20.0, 3.0, 4.0, 30.0, 40.0]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_gemm_linear_layer() -> Result<(), Box<dyn std::error::Error>> {
+    // out = x·Wᵀ + b, with the bias preloaded into the output.
+    let x = arange(&[2, 3]);
+    let w = arange(&[4, 3]);
+    let bias = Tensor::from([1.0, 2.0, 3.0, 4.0]);
+    let mut out = Tensor::from_data(vec![2, 4], vec![0.0; 8])?;
+    out.zip_mut_with(&bias, |o, &b| *o = b)?;
+    gemm(1.0, &x, false, &w, true, 1.0, &mut out)?;
+
+    let expected = x.matmul(&w.transpose(0, 1)?)? + bias;
+    assert_eq!(out.get_data(), expected.get_data());
+    Ok(())
+}
+
+#[test]
+fn test_gemm_scaling_and_transposes() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[3, 2]);
+    let b = arange(&[4, 3]);
+    let mut c = Tensor::from_data(vec![2, 4], vec![1.0; 8])?;
+    gemm(2.0, &a, true, &b, true, 3.0, &mut c)?;
+
+    let product = a.transpose(0, 1)?.matmul(&b.transpose(0, 1)?)?;
+    let expected: Vec<f64> = product.get_data().iter().map(|p| 2.0 * p + 3.0).collect();
+    assert_eq!(c.get_data(), expected);
+
+    // A zero beta overwrites the output, even where it holds NaN.
+    let mut c = Tensor::from_data(vec![2, 4], vec![f64::NAN; 8])?;
+    gemm(1.0, &a, true, &b, true, 0.0, &mut c)?;
+    assert_eq!(c.get_data(), product.get_data());
+
+    let mut wrong = Tensor::from_data(vec![4, 2], vec![0.0; 8])?;
+    assert_eq!(
+        gemm(1.0, &a, true, &b, true, 0.0, &mut wrong).unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![2, 4],
+            actual: vec![4, 2]
+        }
+    );
+    assert_eq!(
+        gemm(1.0, &a, false, &b, false, 0.0, &mut wrong).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 2,
+            actual: 4
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_gemm_into_strided_output() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 2]);
+    let b = Tensor::from([[1.0, 0.0], [0.0, 1.0]]);
+    // Results land at the logical positions of a strided output.
+    let mut c = Tensor::from_data(vec![2, 2], vec![0.0; 4])?.transpose(0, 1)?;
+    gemm(1.0, &a, false, &b, false, 0.0, &mut c)?;
+    assert_eq!(c.contiguous().get_data(), vec![0.0, 1.0, 2.0, 3.0]);
+    Ok(())
+}
+
+#[test]
+fn test_gemv_axpy_ger() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 3]);
+    let mut y = Tensor::from([1.0, 1.0]);
+    gemv(1.0, &a, false, &Tensor::from([1.0, 1.0, 1.0]), 2.0, &mut y)?;
+    assert_eq!(y.get_data(), vec![5.0, 14.0]);
+
+    let mut y = Tensor::from([0.0, 0.0, 0.0]);
+    gemv(1.0, &a, true, &Tensor::from([1.0, 2.0]), 0.0, &mut y)?;
+    assert_eq!(y.get_data(), vec![6.0, 9.0, 12.0]);
+
+    let mut y = Tensor::from([1.0, 2.0, 3.0]);
+    axpy(2.0, &Tensor::from([1.0, 1.0, 1.0]), &mut y)?;
+    assert_eq!(y.get_data(), vec![3.0, 4.0, 5.0]);
+
+    let mut m = Tensor::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]);
+    ger(
+        2.0,
+        &Tensor::from([1.0, 2.0]),
+        &Tensor::from([1.0, 0.0, -1.0]),
+        &mut m,
+    )?;
+    assert_eq!(m.get_data(), vec![3.0, 1.0, -1.0, 5.0, 1.0, -3.0]);
+    Ok(())
+}
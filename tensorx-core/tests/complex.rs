@@ -0,0 +1,90 @@
+use std::f64::consts::PI;
+
+use tensorx_core::complex::{Complex, Complex32, Complex64};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn c(re: f64, im: f64) -> Complex64 {
+    Complex::new(re, im)
+}
+
+fn assert_close(a: Complex64, b: Complex64) {
+    assert!((a - b).abs() < 1e-12, "{a:?} != {b:?}");
+}
+
+#[test]
+fn test_complex_scalar_math() {
+    let z = c(3.0, -4.0);
+    assert_eq!(z.abs(), 5.0);
+    assert_eq!(z.conj(), c(3.0, 4.0));
+    assert_eq!(z * z.conj(), c(25.0, 0.0));
+    assert_close(z / c(0.0, 1.0), c(-4.0, -3.0));
+    assert!((c(-1.0, 0.0).arg() - PI).abs() < 1e-15);
+
+    assert_close(c(0.0, PI).exp(), c(-1.0, 0.0));
+    assert_close(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_close(z.ln().exp(), z);
+    assert_close(Complex::from_polar(2.0, PI / 2.0), c(0.0, 2.0));
+}
+
+#[test]
+fn test_complex_tensor_parts() -> Result<(), Box<dyn std::error::Error>> {
+    let z = Tensor::from_parts(&tensor![[1.0, 0.0], [-2.0, 3.0]], &tensor![1.0, -1.0])?;
+    assert_eq!(z.get_shape(), &[2, 2]);
+    assert_eq!(z.get(&[1, 1])?, &c(3.0, -1.0));
+
+    assert_eq!(z.real()?.get_data(), &[1.0, 0.0, -2.0, 3.0]);
+    assert_eq!(z.imag()?.get_data(), &[1.0, -1.0, 1.0, -1.0]);
+    assert_eq!(z.conj()?.imag()?.get_data(), &[-1.0, 1.0, -1.0, 1.0]);
+    assert_eq!(z.abs()?.get(&[0, 1])?, &1.0);
+    assert!((z.angle()?.get(&[0, 0])? - PI / 4.0).abs() < 1e-15);
+    Ok(())
+}
+
+#[test]
+fn test_complex_arithmetic_and_reductions() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![c(1.0, 1.0), c(2.0, -1.0)];
+    let b = tensor![c(0.0, 1.0)];
+    assert_eq!((&a * &b).get_data(), &[c(-1.0, 1.0), c(1.0, 2.0)]);
+    assert_eq!((&a - &a).get_data(), &[c(0.0, 0.0); 2]);
+    assert_eq!(a.sum(None)?.get_data(), &[c(3.0, 0.0)]);
+
+    // (1 + i)(1 - i) + (2i)(i) = 2 - 2 = 0 on the diagonal.
+    let m = tensor![[c(1.0, 1.0), c(0.0, 2.0)], [c(0.0, 0.0), c(1.0, 0.0)]];
+    let n = tensor![[c(1.0, -1.0), c(0.0, 0.0)], [c(0.0, 1.0), c(1.0, 0.0)]];
+    let p = m.matmul(&n)?;
+    assert_eq!(
+        p.get_data(),
+        &[c(0.0, 0.0), c(0.0, 2.0), c(0.0, 1.0), c(1.0, 0.0)]
+    );
+
+    let e = tensor![c(0.0, PI / 2.0)].exp()?;
+    assert_close(*e.get(&[0])?, c(0.0, 1.0));
+    Ok(())
+}
+
+#[test]
+fn test_complex_display() {
+    let z = tensor![Complex32::new(1.0, -2.5), Complex32::new(0.0, 1.0)];
+    assert_eq!(format!("{z:.1}"), "[1.0-2.5j 0.0+1.0j]");
+}
+
+#[test]
+fn test_serde_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let z = tensor![[c(1.0, -1.0), c(0.5, 2.0)], [c(0.0, 0.0), c(-3.0, 0.25)]];
+    let json = z.to_json()?;
+    assert_eq!(
+        json,
+        r#"{"shape":[2,2],"data":[{"re":1.0,"im":-1.0},{"re":0.5,"im":2.0},{"re":0.0,"im":0.0},{"re":-3.0,"im":0.25}]}"#
+    );
+    let back = Tensor::<Complex64>::from_json(&json)?;
+    assert_eq!(back.get_shape(), z.get_shape());
+    assert_eq!(back.get_data(), z.get_data());
+
+    // Strided views are written in logical order.
+    let t = tensor![[1, 2], [3, 4]].transpose(0, 1)?;
+    assert_eq!(t.to_json()?, r#"{"shape":[2,2],"data":[1,3,2,4]}"#);
+
+    assert!(Tensor::<i32>::from_json(r#"{"shape":[3],"data":[1,2]}"#).is_err());
+    Ok(())
+}
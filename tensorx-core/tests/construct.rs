@@ -0,0 +1,85 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_tensor_macro() {
+    let a = tensor![1.0, 2.0, 3.0];
+    assert_eq!(a.get_shape(), &[3]);
+
+    let b = tensor![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(b.get_shape(), &[2, 3]);
+    assert_eq!(b.get_data(), &[1, 2, 3, 4, 5, 6]);
+
+    let c = tensor![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+    assert_eq!(c.get_shape(), &[2, 2, 2]);
+    assert_eq!(c.get_strides(), &[4, 2, 1]);
+    assert_eq!(c.get_data(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_from_arrays_and_vecs() {
+    let a = Tensor::from([1u8, 2, 3]);
+    assert_eq!(a.get_shape(), &[3]);
+
+    let b = Tensor::from([[[1.0f32], [2.0]], [[3.0], [4.0]], [[5.0], [6.0]]]);
+    assert_eq!(b.get_shape(), &[3, 2, 1]);
+
+    let c = Tensor::from(vec![1, 2, 3, 4]);
+    assert_eq!(c.get_shape(), &[4]);
+
+    let d = Tensor::from_vec(vec![true, false]);
+    assert_eq!(d.get_data(), &[true, false]);
+
+    let e: Tensor<i32> = (0..5).collect();
+    assert_eq!(e.get_shape(), &[5]);
+    assert_eq!(e.get_data(), &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_from_nested_vec() -> Result<(), Box<dyn std::error::Error>> {
+    let t = Tensor::from_nested_vec(vec![vec![vec![1, 2]], vec![vec![3, 4]]])?;
+    assert_eq!(t.get_shape(), &[2, 1, 2]);
+    assert_eq!(t.get_data(), &[1, 2, 3, 4]);
+
+    let ragged = Tensor::from_nested_vec(vec![vec![1, 2], vec![3]]);
+    assert_eq!(
+        ragged.unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![2],
+            actual: vec![1],
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_from_arrays_of_vecs() -> Result<(), Box<dyn std::error::Error>> {
+    let t = Tensor::from_nested(&[vec![1, 2], vec![3, 4]])?;
+    assert_eq!(t.get_shape(), &[2, 2]);
+    assert_eq!(t.get_data(), &[1, 2, 3, 4]);
+
+    assert_eq!(
+        Tensor::from_nested(&[vec![1, 2], vec![3]]).unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![2],
+            actual: vec![1],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_empty_input_keeps_rank() -> Result<(), Box<dyn std::error::Error>> {
+    let rows: [[f32; 3]; 0] = [];
+    let t = Tensor::from(rows);
+    assert_eq!(t.get_shape(), &[0, 3]);
+    assert!(t.get_data().is_empty());
+
+    let t = Tensor::<i32>::from_nested_vec(Vec::<Vec<Vec<i32>>>::new())?;
+    assert_eq!(t.get_shape(), &[0, 0, 0]);
+    let t = Tensor::from_nested_vec(vec![Vec::<[u8; 2]>::new()])?;
+    assert_eq!(t.get_shape(), &[1, 0, 2]);
+    Ok(())
+}
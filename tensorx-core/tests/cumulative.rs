@@ -0,0 +1,94 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_cumsum_and_cumprod() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(t.cumsum(1)?.get_data(), vec![1, 3, 6, 4, 9, 15]);
+    assert_eq!(t.cumsum(0)?.get_data(), vec![1, 2, 3, 5, 7, 9]);
+    assert_eq!(t.cumprod(1)?.get_data(), vec![1, 2, 6, 4, 20, 120]);
+
+    // Strided inputs scan in logical order.
+    let transposed = t.transpose(0, 1)?.cumsum(1)?;
+    assert_eq!(transposed.get_shape(), &[3, 2]);
+    assert_eq!(transposed.get_data(), vec![1, 5, 2, 7, 3, 9]);
+
+    assert_eq!(
+        t.cumsum(2).unwrap_err(),
+        TensorError::InvalidAxis { axis: 2, rank: 2 }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cummax_and_cummin() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![3.0, 1.0, 4.0, 1.0, 5.0];
+    assert_eq!(t.cummax(0)?.get_data(), vec![3.0, 3.0, 4.0, 4.0, 5.0]);
+    assert_eq!(t.cummin(0)?.get_data(), vec![3.0, 1.0, 1.0, 1.0, 1.0]);
+
+    let with_nan = tensor![1.0, f64::NAN, 2.0].cummax(0)?;
+    assert_eq!(with_nan.get_data()[0], 1.0);
+    assert!(with_nan.get_data()[1..].iter().all(|x| x.is_nan()));
+    Ok(())
+}
+
+#[test]
+fn test_logcumsumexp() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![0.0f64, 1.0, 2.0];
+    let expected: Vec<f64> = t
+        .exp()?
+        .cumsum(0)?
+        .get_data()
+        .iter()
+        .map(|x| x.ln())
+        .collect();
+    for (got, want) in t.logcumsumexp(0)?.get_data().iter().zip(&expected) {
+        assert!((got - want).abs() < 1e-12);
+    }
+
+    // Large inputs stay finite, and -inf contributes nothing.
+    let big = tensor![1000.0f64, 1000.0, f64::NEG_INFINITY].logcumsumexp(0)?;
+    let expected = 1000.0 + 2f64.ln();
+    assert_eq!(big.get_data()[0], 1000.0);
+    assert!((big.get_data()[1] - expected).abs() < 1e-9);
+    assert_eq!(big.get_data()[2], big.get_data()[1]);
+    Ok(())
+}
+
+#[test]
+fn test_diff() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 4, 9, 16], [0, 1, 0, 1]];
+    let first = t.diff(1, 1)?;
+    assert_eq!(first.get_shape(), &[2, 3]);
+    assert_eq!(first.get_data(), vec![3, 5, 7, 1, -1, 1]);
+
+    let second = t.diff(2, 1)?;
+    assert_eq!(second.get_data(), vec![2, 2, -2, 2]);
+
+    assert_eq!(t.diff(1, 0)?.get_data(), vec![-1, -3, -9, -15]);
+    assert_eq!(t.diff(0, 1)?.get_data(), t.get_data());
+    assert_eq!(t.diff(7, 1)?.get_shape(), &[2, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_custom_scan() -> Result<(), Box<dyn std::error::Error>> {
+    // Any associative operator works, including on non-numeric elements.
+    let words: Tensor<String> = tensor![1, 2, 3].map(|x| x.to_string());
+    let joined = words.scan(0, |a, b| format!("{a}{b}"))?;
+    assert_eq!(joined.get_data(), vec!["1", "12", "123"]);
+
+    let gcd = |a: &u32, b: &u32| {
+        let (mut a, mut b) = (*a, *b);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    assert_eq!(
+        tensor![12u32, 18, 8, 7].scan(0, gcd)?.get_data(),
+        vec![12, 6, 2, 1]
+    );
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use tensorx_core::display::{PrintOptions, print_options, with_print_options};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_display_matrix() {
+    let t = tensor![[1, 2, 3], [4, 5, 60]];
+    assert_eq!(t.to_string(), "[[ 1  2  3]\n [ 4  5 60]]");
+
+    let f = tensor![[1.0, 2.5], [-3.0, 4.0]];
+    assert_eq!(format!("{f:.1}"), "[[ 1.0  2.5]\n [-3.0  4.0]]");
+}
+
+#[test]
+fn test_display_logical_order() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6]].permute(&[1, 0])?;
+    assert_eq!(t.to_string(), "[[1 4]\n [2 5]\n [3 6]]");
+
+    let cube = tensor![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+    assert_eq!(cube.to_string(), "[[[1 2]\n  [3 4]]\n\n [[5 6]\n  [7 8]]]");
+
+    let view = cube.view().slice(0, 1..2)?;
+    assert_eq!(view.to_string(), "[[[5 6]\n  [7 8]]]");
+
+    Ok(())
+}
+
+#[test]
+fn test_display_summarized() {
+    let t: Tensor<i32> = (0..2000).collect();
+    assert_eq!(t.to_string(), "[   0    1    2  ... 1997 1998 1999]");
+}
+
+#[test]
+fn test_scoped_print_options() {
+    let t = tensor![0.5f32, 1.25];
+    let options = PrintOptions {
+        precision: 2,
+        line_width: 8,
+        ..PrintOptions::default()
+    };
+
+    let rendered = with_print_options(options, || t.to_string());
+    assert_eq!(rendered, "[0.50\n 1.25]");
+    assert_eq!(print_options(), PrintOptions::default());
+    assert_eq!(t.to_string(), "[0.5000 1.2500]");
+}
+
+#[test]
+fn test_display_reports_invalid_layout() {
+    use std::fmt::Write;
+
+    let t = tensor![[1, 2], [3, 4]];
+    let view = t.view().reshape(vec![3, 3], vec![3, 1]);
+    let mut out = String::new();
+    assert!(write!(out, "{view}").is_err());
+}
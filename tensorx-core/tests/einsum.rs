@@ -0,0 +1,163 @@
+use tensorx_core::einsum::einsum;
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn arange(shape: &[usize]) -> Tensor<f64> {
+    let n = shape.iter().product();
+    Tensor::from_data(shape.to_vec(), (0..n).map(|x| x as f64).collect()).unwrap()
+}
+
+#[test]
+fn test_einsum_matches_matmul() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 3]);
+    let b = arange(&[3, 4]);
+    let expected = a.matmul(&b)?;
+    assert_eq!(
+        einsum("ij,jk->ik", &[&a, &b])?.get_data(),
+        expected.get_data()
+    );
+    // Implicit output: the labels used once, in alphabetical order.
+    assert_eq!(einsum("ij,jk", &[&a, &b])?.get_data(), expected.get_data());
+
+    let transposed = einsum("ij,jk->ki", &[&a, &b])?;
+    assert_eq!(transposed.get_shape(), &[4, 2]);
+    assert_eq!(
+        transposed.get_data(),
+        expected.transpose(0, 1)?.contiguous().get_data()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_einsum_batched_matmul() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 2, 3]);
+    let b = arange(&[2, 3, 2]);
+    let c = einsum("bij,bjk->bik", &[&a, &b])?;
+    assert_eq!(c.get_shape(), &[2, 2, 2]);
+    for batch in 0..2 {
+        let lhs: Tensor<f64> = a
+            .axis_iter(0)?
+            .nth(batch)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        let rhs: Tensor<f64> = b
+            .axis_iter(0)?
+            .nth(batch)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        let expected = lhs.reshape(vec![2, 3])?.matmul(&rhs.reshape(vec![3, 2])?)?;
+        let actual: Vec<f64> = c
+            .axis_iter(0)?
+            .nth(batch)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(actual, expected.get_data());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_einsum_traces_diagonals_and_sums() -> Result<(), Box<dyn std::error::Error>> {
+    let m = arange(&[3, 3]);
+    assert_eq!(einsum("ii", &[&m])?.get_data(), &[12.0]);
+    assert_eq!(einsum("ii->i", &[&m])?.get_data(), &[0.0, 4.0, 8.0]);
+    assert_eq!(einsum("ij->j", &[&m])?.get_data(), &[9.0, 12.0, 15.0]);
+    assert_eq!(einsum("ij->", &[&m])?.get_shape(), &[] as &[usize]);
+    assert_eq!(
+        einsum("ji", &[&m])?.get_data(),
+        m.transpose(0, 1)?.contiguous().get_data()
+    );
+
+    let v = tensor![1.0, 2.0, 3.0];
+    let w = tensor![4.0, 5.0, 6.0];
+    assert_eq!(einsum("i,i->", &[&v, &w])?.get_data(), &[32.0]);
+    assert_eq!(einsum("i,j->ij", &[&v, &w])?.get(&[2, 1])?, &15.0);
+    Ok(())
+}
+
+#[test]
+fn test_einsum_chain_of_operands() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 3]);
+    let b = arange(&[3, 4]);
+    let c = arange(&[4, 5]);
+    let d = arange(&[5, 2]);
+    let chained = einsum("ij,jk,kl,lm->im", &[&a, &b, &c, &d])?;
+    let expected = a.matmul(&b)?.matmul(&c)?.matmul(&d)?;
+    assert_eq!(chained.get_data(), expected.get_data());
+
+    // Scalar result of a bilinear form.
+    let x = tensor![1.0, 2.0];
+    let y = tensor![3.0, 4.0, 5.0];
+    let form = einsum("i,ij,j->", &[&x, &arange(&[2, 3]), &y])?;
+    assert_eq!(form.get_data(), &[1.0 * 14.0 + 2.0 * 50.0]);
+    Ok(())
+}
+
+#[test]
+fn test_einsum_ellipsis_broadcasting() -> Result<(), Box<dyn std::error::Error>> {
+    let a = arange(&[2, 1, 2, 3]);
+    let b = arange(&[4, 3, 2]);
+    let c = einsum("...ij,...jk->...ik", &[&a, &b])?;
+    assert_eq!(c.get_shape(), &[2, 4, 2, 2]);
+
+    let lhs = arange(&[2, 3]);
+    let rhs: Tensor<f64> = b.axis_iter(0)?.nth(1).unwrap().iter().copied().collect();
+    let expected = lhs.matmul(&rhs.reshape(vec![3, 2])?)?;
+    let actual: Vec<f64> = c
+        .axis_iter(0)?
+        .next()
+        .unwrap()
+        .axis_iter(0)?
+        .nth(1)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(actual, expected.get_data());
+
+    // Implicit output keeps the broadcast axes in front.
+    assert_eq!(
+        einsum("...i,...i", &[&arange(&[2, 3]), &arange(&[3])])?.get_data(),
+        &[5.0, 14.0]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_einsum_errors() {
+    let a = arange(&[2, 3]);
+    let b = arange(&[2, 3]);
+    assert_eq!(
+        einsum("ij,jk->ik", &[&a, &b]).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 3,
+            actual: 2
+        }
+    );
+    assert_eq!(
+        einsum("ijk", &[&a]).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 3,
+            actual: 2
+        }
+    );
+    assert!(matches!(
+        einsum("ij,jk", &[&a]),
+        Err(TensorError::InvalidSubscripts(_))
+    ));
+    assert!(matches!(
+        einsum("ij->iz", &[&a]),
+        Err(TensorError::InvalidSubscripts(_))
+    ));
+    assert!(matches!(
+        einsum("i1", &[&a]),
+        Err(TensorError::InvalidSubscripts(_))
+    ));
+}
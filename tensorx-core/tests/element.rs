@@ -0,0 +1,47 @@
+use tensorx_core::element::{Float, Int, Num, Real};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn mean<T: Num>(t: &Tensor<T>) -> T {
+    t.iter().fold(T::zero(), |acc, &x| acc + x) / T::from_usize(t.numel())
+}
+
+fn clip<T: Real>(t: &Tensor<T>, lo: T, hi: T) -> Tensor<T> {
+    t.map(|&x| x.maximum(lo).minimum(hi))
+}
+
+#[test]
+fn test_num_generic_code() {
+    assert_eq!(mean(&tensor![1, 2, 3, 6]), 3);
+    assert_eq!(mean(&tensor![1.0, 2.0]), 1.5);
+    assert_eq!(clip(&tensor![-5i8, 0, 5], -1, 1).get_data(), &[-1, 0, 1]);
+    assert_eq!(
+        tensor![[1u8, 2], [3, 4]].sum(None).unwrap().get_data(),
+        &[10]
+    );
+}
+
+#[test]
+fn test_casting() {
+    assert_eq!(2.9f64.cast::<i32>(), 2);
+    assert_eq!((-1.0f32).cast::<u8>(), 0);
+    assert_eq!(300i32.cast::<u8>(), 255);
+    assert_eq!(f32::nan().cast::<i64>(), 0);
+    assert_eq!(7u16.cast::<f64>(), 7.0);
+    assert_eq!(<i16 as Real>::min_value(), i16::MIN);
+    assert_eq!(<u32 as Int>::BITS, 32);
+}
+
+#[test]
+fn test_nan_awareness() {
+    let nan = f64::nan();
+    assert!(nan.is_nan());
+    assert!(!3i32.is_nan());
+    assert!(Real::maximum(nan, 1.0).is_nan());
+    assert!(Real::minimum(1.0, nan).is_nan());
+    assert_eq!(Real::maximum(2.0, 1.0), 2.0);
+
+    let relu = tensor![nan, -1.0].relu().unwrap();
+    assert!(relu.get(&[0]).unwrap().is_nan());
+    assert_eq!(relu.get(&[1]).unwrap(), &0.0);
+}
@@ -0,0 +1,117 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+use tensorx_core::tensor::{Tensor, compute_strides};
+
+#[test]
+fn test_errors_carry_context() {
+    let err = Tensor::from_data(vec![2, 3], vec![1, 2, 3]).unwrap_err();
+    assert_eq!(
+        err,
+        TensorError::ShapeMismatch {
+            expected: vec![2, 3],
+            actual: vec![3],
+        }
+    );
+
+    let a = tensor![[1, 2], [3, 4]];
+    let b = tensor![1, 2];
+    assert_eq!(
+        a.elementwise_add(&tensor![1, 2, 3]).unwrap_err(),
+        TensorError::BroadcastError {
+            lhs: vec![2, 2],
+            rhs: vec![3],
+        }
+    );
+
+    assert_eq!(
+        a.get(&[0, 2]).unwrap_err(),
+        TensorError::IndexOutOfBounds {
+            index: vec![0, 2],
+            shape: vec![2, 2],
+        }
+    );
+    assert_eq!(
+        a.transpose(0, 2).unwrap_err(),
+        TensorError::InvalidAxis { axis: 2, rank: 2 }
+    );
+    assert_eq!(
+        b.matmul(&a).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 2,
+            actual: 1
+        }
+    );
+    assert!(a.view().slice(1, 1..3).is_err());
+}
+
+#[test]
+fn test_error_messages_are_not_empty() {
+    let errors = [
+        TensorError::MixedStorage,
+        TensorError::IncompatibleTypes("f32 and i32"),
+        TensorError::BroadcastError {
+            lhs: vec![2, 3],
+            rhs: vec![4],
+        },
+    ];
+    for err in errors {
+        assert!(!err.to_string().is_empty());
+    }
+    assert_eq!(
+        TensorError::BroadcastError {
+            lhs: vec![2, 3],
+            rhs: vec![4],
+        }
+        .to_string(),
+        "Shapes [2, 3] and [4] cannot be broadcast together"
+    );
+}
+
+#[test]
+fn test_reshape_and_contiguous() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(compute_strides(&[]).is_empty());
+
+    let t = tensor![[1, 2, 3], [4, 5, 6]].transpose(0, 1)?;
+    assert!(!t.is_contiguous());
+    assert_eq!(t.contiguous().get_data(), &[1, 4, 2, 5, 3, 6]);
+
+    let r = t.reshape(vec![6])?;
+    assert_eq!(r.get_shape(), &[6]);
+    assert_eq!(r.get_data(), &[1, 4, 2, 5, 3, 6]);
+
+    assert!(matches!(
+        t.reshape(vec![4]),
+        Err(TensorError::ShapeMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_setters_keep_elements_in_bounds() -> Result<(), Box<dyn std::error::Error>> {
+    let mut t = tensor![[1, 2], [3, 4]];
+    assert_eq!(
+        t.set_shape(vec![3, 3]).unwrap_err(),
+        TensorError::IndexOutOfBounds {
+            index: vec![6],
+            shape: vec![4],
+        }
+    );
+    assert!(t.set_strides(vec![3, 1]).is_err());
+    assert!(t.set_offset(1).is_err());
+    assert_eq!(t.get_shape(), &[2, 2]);
+    assert_eq!(t.contiguous().get_data(), &[1, 2, 3, 4]);
+
+    // A valid layout over the same data: the second column.
+    t.set_shape(vec![2, 1])?;
+    t.set_offset(1)?;
+    assert_eq!(t.contiguous().get_data(), &[2, 4]);
+    t.set_strides(vec![0, 0])?;
+    assert_eq!(t.contiguous().get_data(), &[2, 2]);
+
+    // Empty layouts reach nothing, but the offset stays within the data.
+    t.set_shape(vec![0, 1])?;
+    t.set_offset(4)?;
+    assert!(t.set_offset(5).is_err());
+    assert!(t.contiguous().get_data().is_empty());
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use tensorx_core::complex::Complex64;
+use tensorx_core::error::TensorError;
+use tensorx_core::fft::{self, FftNorm};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn assert_close(actual: &Tensor<Complex64>, expected: &Tensor<Complex64>) {
+    assert_eq!(actual.get_shape(), expected.get_shape());
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!(
+            (a.re - e.re).abs() < 1e-9 && (a.im - e.im).abs() < 1e-9,
+            "{a:?} != {e:?}"
+        );
+    }
+}
+
+fn signal(n: usize) -> Tensor<Complex64> {
+    (0..n)
+        .map(|k| Complex64::new((k as f64 * 0.7).sin() + 1.0, (k as f64 * 1.3).cos()))
+        .collect()
+}
+
+fn naive_dft(x: &Tensor<Complex64>) -> Tensor<Complex64> {
+    let x: Vec<Complex64> = x.iter().copied().collect();
+    let n = x.len();
+    (0..n)
+        .map(|k| {
+            x.iter()
+                .enumerate()
+                .fold(Complex64::default(), |acc, (j, &v)| {
+                    let theta = -2.0 * std::f64::consts::PI * (j * k) as f64 / n as f64;
+                    acc + v * Complex64::cis(theta)
+                })
+        })
+        .collect()
+}
+
+#[test]
+fn test_fft_matches_naive_dft() -> Result<(), Box<dyn std::error::Error>> {
+    // Powers of two take the radix-2 path, the rest go through Bluestein.
+    for n in [1, 2, 3, 5, 8, 12, 16, 17, 30] {
+        let x = signal(n);
+        let spectrum = fft::fft(&x, None, 0, FftNorm::Backward)?;
+        assert_close(&spectrum, &naive_dft(&x));
+        assert_close(&fft::ifft(&spectrum, None, 0, FftNorm::Backward)?, &x);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fft_norm_modes_and_padding() -> Result<(), Box<dyn std::error::Error>> {
+    let x = signal(6);
+    let plain = fft::fft(&x, None, 0, FftNorm::Backward)?;
+
+    let ortho = fft::fft(&x, None, 0, FftNorm::Ortho)?;
+    assert_close(&ortho, &plain.map(|z| z.scale(1.0 / 6f64.sqrt())));
+    assert_close(&fft::ifft(&ortho, None, 0, FftNorm::Ortho)?, &x);
+
+    let forward = fft::fft(&x, None, 0, FftNorm::Forward)?;
+    assert_close(&forward, &plain.map(|z| z.scale(1.0 / 6.0)));
+    assert_close(&fft::ifft(&forward, None, 0, FftNorm::Forward)?, &x);
+
+    let padded = fft::fft(&x, Some(8), 0, FftNorm::Backward)?;
+    let mut zero_padded: Vec<Complex64> = x.iter().copied().collect();
+    zero_padded.resize(8, Complex64::default());
+    assert_close(&padded, &naive_dft(&Tensor::from(zero_padded)));
+
+    let cropped = fft::fft(&x, Some(4), 0, FftNorm::Backward)?;
+    let head: Tensor<Complex64> = x.iter().copied().take(4).collect();
+    assert_close(&cropped, &naive_dft(&head));
+    Ok(())
+}
+
+#[test]
+fn test_fft_along_axis_and_fft2() -> Result<(), Box<dyn std::error::Error>> {
+    let rows = signal(12).reshape(vec![3, 4])?;
+
+    let along_rows = fft::fft(&rows, None, 1, FftNorm::Backward)?;
+    for (i, row) in rows.axis_iter(0)?.enumerate() {
+        let row: Tensor<Complex64> = row.iter().copied().collect();
+        let expected = naive_dft(&row);
+        let actual: Tensor<Complex64> = along_rows
+            .axis_iter(0)?
+            .nth(i)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        assert_close(&actual, &expected);
+    }
+
+    let both = fft::fft2(&rows, FftNorm::Backward)?;
+    let by_axes = fft::fft(&along_rows, None, 0, FftNorm::Backward)?;
+    assert_close(&both, &by_axes);
+    assert_close(&fft::fftn(&rows, None, FftNorm::Backward)?, &both);
+    assert_close(&fft::ifft2(&both, FftNorm::Backward)?, &rows);
+    assert_close(&fft::ifftn(&both, Some(&[0, 1]), FftNorm::Backward)?, &rows);
+    Ok(())
+}
+
+#[test]
+fn test_rfft_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    for n in [7usize, 8] {
+        let x: Tensor<f64> = (0..n).map(|k| (k as f64).powi(2) - 3.0).collect();
+        let half = fft::rfft(&x, None, 0, FftNorm::Backward)?;
+        assert_eq!(half.get_shape(), &[n / 2 + 1]);
+
+        let full = fft::fft(&x.map(|&v| Complex64::from(v)), None, 0, FftNorm::Backward)?;
+        let head: Tensor<Complex64> = full.iter().copied().take(n / 2 + 1).collect();
+        assert_close(&half, &head);
+
+        let back = fft::irfft(&half, Some(n), 0, FftNorm::Backward)?;
+        for (a, e) in back.iter().zip(x.iter()) {
+            assert!((a - e).abs() < 1e-9);
+        }
+    }
+
+    let default_len = fft::irfft(
+        &fft::rfft(&tensor![1.0, 2.0, 3.0, 4.0], None, 0, FftNorm::Ortho)?,
+        None,
+        0,
+        FftNorm::Ortho,
+    )?;
+    assert_eq!(default_len.get_shape(), &[4]);
+
+    // Zero points, given or implied by an empty axis or a single frequency.
+    let error = TensorError::InvalidArgument("a real FFT needs at least one point".into());
+    let x = tensor![1.0, 2.0];
+    assert_eq!(
+        fft::rfft(&x, Some(0), 0, FftNorm::Forward).unwrap_err(),
+        error
+    );
+    let empty = Tensor::<f64>::new(vec![2, 0], 0.0);
+    assert_eq!(
+        fft::rfft(&empty, None, 1, FftNorm::Forward).unwrap_err(),
+        error
+    );
+    let half = fft::rfft(&x, None, 0, FftNorm::Backward)?;
+    assert_eq!(
+        fft::irfft(&half, Some(0), 0, FftNorm::Backward).unwrap_err(),
+        error
+    );
+    let dc = tensor![Complex64::from(1.0)];
+    assert_eq!(
+        fft::irfft(&dc, None, 0, FftNorm::Backward).unwrap_err(),
+        error
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fftfreq_and_shift() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(fft::fftfreq(4, 0.5).get_data(), &[0.0, 0.5, -1.0, -0.5]);
+    assert_eq!(
+        fft::fftfreq(5, 1.0).get_data(),
+        &[0.0, 0.2, 0.4, -0.4, -0.2]
+    );
+    assert_eq!(fft::rfftfreq(5, 1.0).get_data(), &[0.0, 0.2, 0.4]);
+
+    let x = tensor![0, 1, 2, -2, -1];
+    let shifted = fft::fftshift(&x, None)?;
+    assert_eq!(shifted.get_data(), &[-2, -1, 0, 1, 2]);
+    assert_eq!(fft::ifftshift(&shifted, None)?.get_data(), x.get_data());
+
+    let grid = tensor![[0, 1, 2], [3, 4, 5]];
+    assert_eq!(
+        fft::fftshift(&grid, Some(&[1]))?.get_data(),
+        &[2, 0, 1, 5, 3, 4]
+    );
+    assert_eq!(fft::fftshift(&grid, None)?.get_data(), &[5, 3, 4, 2, 0, 1]);
+    Ok(())
+}
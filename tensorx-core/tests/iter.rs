@@ -0,0 +1,96 @@
+use tensorx_core::tensor;
+
+#[test]
+fn test_iter_logical_order() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6]].transpose(0, 1)?;
+    let values: Vec<i32> = t.iter().copied().collect();
+    assert_eq!(values, vec![1, 4, 2, 5, 3, 6]);
+
+    let indexed: Vec<(Vec<usize>, i32)> = t.indexed_iter().map(|(i, &v)| (i, v)).collect();
+    assert_eq!(indexed[1], (vec![0, 1], 4));
+    assert_eq!(indexed[5], (vec![2, 1], 6));
+
+    let view = t.view().slice(0, 1..3)?;
+    assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![2, 5, 3, 6]);
+    Ok(())
+}
+
+#[test]
+fn test_iter_mut() -> Result<(), Box<dyn std::error::Error>> {
+    let mut t = tensor![[1, 2], [3, 4]].transpose(0, 1)?;
+    for (k, x) in t.iter_mut().enumerate() {
+        *x += 10 * k as i32;
+    }
+    assert_eq!(t.contiguous().get_data(), &[1, 13, 22, 34]);
+
+    let mut view = t.view_mut();
+    let mut row = view.slice_mut(0, 1..2)?;
+    row.iter_mut().for_each(|x| *x = 0);
+    assert_eq!(t.iter().copied().collect::<Vec<_>>(), vec![1, 13, 0, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_axis_iter_and_lanes() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+
+    let blocks: Vec<Vec<i32>> = t
+        .axis_iter(1)?
+        .map(|v| v.iter().copied().collect())
+        .collect();
+    assert_eq!(blocks, vec![vec![1, 2, 5, 6], vec![3, 4, 7, 8]]);
+    assert_eq!(
+        t.axis_iter(1)?.next().map(|v| v.get_shape().to_vec()),
+        Some(vec![2, 2])
+    );
+
+    let lanes: Vec<Vec<i32>> = t.lanes(0)?.map(|v| v.iter().copied().collect()).collect();
+    assert_eq!(lanes, vec![vec![1, 5], vec![2, 6], vec![3, 7], vec![4, 8]]);
+
+    assert!(t.lanes(3).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_windows() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+    let sums: Vec<i32> = t.windows(&[2, 2])?.map(|w| w.iter().sum()).collect();
+    assert_eq!(sums, vec![12, 16, 24, 28]);
+
+    let row = tensor![1, 2, 3, 4];
+    assert_eq!(row.windows(&[3])?.count(), 2);
+    assert_eq!(row.windows(&[5])?.count(), 0);
+    assert!(row.windows(&[1, 1]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_iter_mut_stops_at_repeated_element() -> Result<(), Box<dyn std::error::Error>> {
+    // Both rows alias the first row of the data.
+    let mut t = tensor![[1, 2], [3, 4]];
+    t.set_strides(vec![0, 1])?;
+    let visited = t.iter_mut().map(|x| *x *= 10).count();
+    assert_eq!(visited, 2);
+    assert_eq!(t.get_data(), &[10, 20, 3, 4]);
+    Ok(())
+}
+
+#[test]
+fn test_view_mut_iterators() -> Result<(), Box<dyn std::error::Error>> {
+    let mut t = tensor![[1, 2, 3], [4, 5, 6]];
+    let mut view = t.view_mut();
+    view.iter_mut().for_each(|x| *x *= 2);
+
+    assert_eq!(
+        view.iter().copied().collect::<Vec<_>>(),
+        vec![2, 4, 6, 8, 10, 12]
+    );
+    assert_eq!(view.indexed_iter().nth(4), Some((vec![1, 1], &10)));
+    let columns: Vec<i32> = view.axis_iter(1)?.map(|c| c.iter().sum()).collect();
+    assert_eq!(columns, vec![10, 14, 18]);
+    assert_eq!(view.lanes(1)?.count(), 2);
+    let windows: Vec<i32> = view.windows(&[2, 2])?.map(|w| w.iter().sum()).collect();
+    assert_eq!(windows, vec![24, 32]);
+    Ok(())
+}
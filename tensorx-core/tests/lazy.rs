@@ -0,0 +1,126 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+
+fn bits(data: &[f64]) -> Vec<u64> {
+    data.iter().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn test_fused_chain_matches_eager() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[0.3, -1.7, 2.2], [0.1, 0.9, -0.4]];
+    let b = tensor![1.1, -0.6, 0.7];
+    let c = tensor![[0.05], [-0.3]];
+
+    let eager = ((&a * &b) + &c).relu()?.exp()?;
+    let lazy = ((a.lazy() * b.lazy()) + c.lazy()).relu().exp();
+    assert_eq!(lazy.shape()?, vec![2, 3]);
+    let fused = lazy.eval()?;
+    assert_eq!(fused.get_shape(), eager.get_shape());
+    assert_eq!(bits(fused.get_data()), bits(eager.get_data()));
+
+    let eager = ((2.0 - &a) / 3.0).sigmoid()?.tanh()?;
+    let lazy = ((2.0 - a.lazy()) / 3.0).sigmoid().tanh();
+    assert_eq!(bits(lazy.get_data()?), bits(eager.get_data()));
+
+    let eager = (-(&a.abs()? + 1.0)).exp()?.sqrt()?.log()?.powf(1.5)?;
+    let lazy = (-(a.lazy().abs() + 1.0)).exp().sqrt().log().powf(1.5);
+    assert_eq!(bits(lazy.get_data()?), bits(eager.get_data()));
+    Ok(())
+}
+
+#[test]
+fn test_evaluation_is_deferred_and_cached() -> Result<(), Box<dyn std::error::Error>> {
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let lazy = (tensor![1i32, 2, 3].lazy() * 2).map(move |x| {
+        counter.set(counter.get() + 1);
+        x + 1
+    });
+    assert_eq!(calls.get(), 0);
+    assert!(!lazy.is_evaluated());
+
+    assert_eq!(lazy.get(&[1])?, &5);
+    assert!(lazy.is_evaluated());
+    assert_eq!(calls.get(), 3);
+    assert_eq!(lazy.get_data()?, &[3, 5, 7]);
+    lazy.eval()?;
+    assert_eq!(calls.get(), 3);
+
+    // A clone shares the graph but evaluates on its own.
+    let copy = lazy.clone();
+    assert!(!copy.is_evaluated());
+    assert_eq!(copy.into_tensor()?.get_data(), &[3, 5, 7]);
+    assert_eq!(calls.get(), 6);
+    Ok(())
+}
+
+#[test]
+fn test_strided_and_shared_inputs() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2], [3, 4]].transpose(0, 1)?;
+    let x = t.into_lazy();
+    let squared_plus = &x * &x + &x;
+    assert_eq!(squared_plus.get_data()?, &[2, 12, 6, 20]);
+    Ok(())
+}
+
+#[test]
+fn test_shape_errors_surface_on_eval() {
+    let lazy = tensor![1.0, 2.0].lazy() + tensor![1.0, 2.0, 3.0].lazy();
+    let error = TensorError::BroadcastError {
+        lhs: vec![2],
+        rhs: vec![3],
+    };
+    assert_eq!(lazy.shape().unwrap_err(), error);
+    assert_eq!(lazy.eval().unwrap_err(), error);
+    assert!(!lazy.is_evaluated());
+}
+
+#[test]
+fn test_shared_subexpressions_evaluate_once() -> Result<(), Box<dyn std::error::Error>> {
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let shared = tensor![1i64, 2, 3].lazy().map(move |x| {
+        counter.set(counter.get() + 1);
+        x
+    });
+
+    // 2^40 paths lead to `shared`, but it is one node.
+    let mut x = shared.clone();
+    for _ in 0..40 {
+        x = &x + &x;
+    }
+    assert_eq!(x.get_data()?, &[1 << 40, 2 << 40, 3 << 40]);
+    assert_eq!(calls.get(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_builds_on_evaluated_results() -> Result<(), Box<dyn std::error::Error>> {
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let base = tensor![1, 2].lazy().map(move |x| {
+        counter.set(counter.get() + 1);
+        x * 10
+    });
+    base.eval()?;
+    assert_eq!(calls.get(), 2);
+
+    let derived = &base + 1i32;
+    assert_eq!(derived.shape()?, vec![2]);
+    assert_eq!(derived.get_data()?, &[11, 21]);
+    assert_eq!(calls.get(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_long_chains() -> Result<(), Box<dyn std::error::Error>> {
+    let mut x = tensor![0.0, 1.0].lazy();
+    for _ in 0..100_000 {
+        x = x + 1.0;
+    }
+    assert_eq!(x.get_data()?, &[100_000.0, 100_001.0]);
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::linalg;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+    assert_eq!(actual.get_shape(), expected.get_shape());
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!((a - e).abs() < 1e-9, "{actual} != {expected}");
+    }
+}
+
+fn eye(n: usize) -> Tensor<f64> {
+    let mut t = Tensor::new(vec![n, n], 0.0);
+    for i in 0..n {
+        t.set(&[i, i], 1.0).unwrap();
+    }
+    t
+}
+
+#[test]
+fn test_lu_reconstructs_input() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[0.0, 2.0, 1.0], [4.0, 1.0, -1.0], [2.0, 3.0, 5.0]];
+    let lu = linalg::lu(&a)?;
+    assert_close(&lu.p.matmul(&lu.l)?.matmul(&lu.u)?, &a);
+    assert_eq!(lu.l.get(&[1, 1])?, &1.0);
+    assert_eq!(lu.u.get(&[2, 0])?, &0.0);
+
+    let wide = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let lu = linalg::lu(&wide)?;
+    assert_eq!(lu.l.get_shape(), &[2, 2]);
+    assert_eq!(lu.u.get_shape(), &[2, 3]);
+    assert_close(&lu.p.matmul(&lu.l)?.matmul(&lu.u)?, &wide);
+    Ok(())
+}
+
+#[test]
+fn test_solve_vector_and_batched() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[3.0, 1.0], [1.0, 2.0]];
+    let x = linalg::solve(&a, &tensor![9.0, 8.0])?;
+    assert_close(&x, &tensor![2.0, 3.0]);
+
+    // Two right-hand sides sharing one matrix through broadcasting.
+    let b = tensor![[[9.0], [8.0]], [[4.0], [3.0]]];
+    let x = linalg::solve(&a, &b)?;
+    assert_eq!(x.get_shape(), &[2, 2, 1]);
+    assert_close(&x, &tensor![[[2.0], [3.0]], [[1.0], [1.0]]]);
+
+    let singular = tensor![[1.0, 2.0], [2.0, 4.0]];
+    assert_eq!(
+        linalg::solve(&singular, &tensor![1.0, 2.0]).unwrap_err(),
+        TensorError::Singular
+    );
+
+    // Singular up to rounding: elimination leaves a pivot of about 1e-16
+    // rather than exactly zero.
+    let nearly = tensor![[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]];
+    assert_eq!(
+        linalg::solve(&nearly, &tensor![1.0, 2.0, 3.0]).unwrap_err(),
+        TensorError::Singular
+    );
+    assert_eq!(linalg::inv(&nearly).unwrap_err(), TensorError::Singular);
+    Ok(())
+}
+
+#[test]
+fn test_right_hand_side_shapes() -> Result<(), Box<dyn std::error::Error>> {
+    let scaled = |scales: &[f64]| {
+        let mut a = Tensor::new(vec![scales.len(), 3, 3], 0.0);
+        for (i, &s) in scales.iter().enumerate() {
+            for j in 0..3 {
+                a.set(&[i, j, j], s).unwrap();
+            }
+        }
+        a
+    };
+
+    // One matrix right-hand side broadcast against a batch of two systems.
+    let a = scaled(&[1.0, 2.0]);
+    let b = tensor![[2.0, 4.0], [6.0, 8.0], [10.0, 12.0]];
+    let expected = tensor![
+        [[2.0, 4.0], [6.0, 8.0], [10.0, 12.0]],
+        [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]
+    ];
+    assert_close(&linalg::solve(&a, &b)?, &expected);
+    assert_close(&linalg::lstsq(&a, &b)?, &expected);
+
+    // A batch of vectors, one per system, with exactly `a`'s batch shape.
+    let a = scaled(&[1.0, 2.0, 4.0]);
+    let b = tensor![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [4.0, 8.0, 12.0]];
+    let expected = tensor![[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [1.0, 2.0, 3.0]];
+    assert_close(&linalg::solve(&a, &b)?, &expected);
+    assert_close(&linalg::lstsq(&a, &b)?, &expected);
+    Ok(())
+}
+
+#[test]
+fn test_inv_det_slogdet() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[4.0, 7.0], [2.0, 6.0]];
+    assert_close(
+        &a.matmul(&linalg::inv(&a)?)?,
+        &tensor![[1.0, 0.0], [0.0, 1.0]],
+    );
+
+    let d = linalg::det(&a)?;
+    assert!(d.get_shape().is_empty());
+    assert!((d.get(&[])? - 10.0).abs() < 1e-12);
+
+    let batch = tensor![[[2.0, 0.0], [0.0, 3.0]], [[0.0, 1.0], [1.0, 0.0]]];
+    assert_close(&linalg::det(&batch)?, &tensor![6.0, -1.0]);
+
+    let (sign, logabs) = linalg::slogdet(&batch)?;
+    assert_close(&sign, &tensor![1.0, -1.0]);
+    assert_close(&logabs, &tensor![6.0f64.ln(), 0.0]);
+
+    let (sign, logabs) = linalg::slogdet(&tensor![[1.0, 2.0], [2.0, 4.0]])?;
+    assert_eq!(sign.get(&[])?, &0.0);
+    assert_eq!(logabs.get(&[])?, &f64::NEG_INFINITY);
+
+    assert_eq!(
+        linalg::inv(&tensor![[1.0, 2.0, 3.0]]).unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![1, 1],
+            actual: vec![1, 3]
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_qr_is_orthonormal_and_triangular() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![
+        [12.0, -51.0, 4.0],
+        [6.0, 167.0, -68.0],
+        [-4.0, 24.0, -41.0],
+        [1.0, 1.0, 1.0]
+    ];
+    let qr = linalg::qr(&a)?;
+    assert_eq!(qr.q.get_shape(), &[4, 3]);
+    assert_eq!(qr.r.get_shape(), &[3, 3]);
+    assert_close(&qr.q.matmul(&qr.r)?, &a);
+
+    let qtq = qr.q.transpose(0, 1)?.matmul(&qr.q)?;
+    assert_close(
+        &qtq,
+        &tensor![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    );
+    assert_eq!(qr.r.get(&[2, 1])?, &0.0);
+    Ok(())
+}
+
+#[test]
+fn test_cholesky() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![
+        [4.0, 12.0, -16.0],
+        [12.0, 37.0, -43.0],
+        [-16.0, -43.0, 98.0]
+    ];
+    let l = linalg::cholesky(&a)?;
+    assert_close(
+        &l,
+        &tensor![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+    );
+    assert_close(&l.matmul(&l.transpose(0, 1)?)?, &a);
+
+    assert_eq!(
+        linalg::cholesky(&tensor![[1.0, 2.0], [2.0, 1.0]]).unwrap_err(),
+        TensorError::NotPositiveDefinite
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lstsq_and_pinv() -> Result<(), Box<dyn std::error::Error>> {
+    // Fit y = 1 + 2x through noiseless points.
+    let a = tensor![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]];
+    let x = linalg::lstsq(&a, &tensor![1.0, 3.0, 5.0, 7.0])?;
+    assert_close(&x, &tensor![1.0, 2.0]);
+
+    // Underdetermined: the minimum norm solution of x + y = 2 is (1, 1).
+    let x = linalg::lstsq(&tensor![[1.0, 1.0]], &tensor![2.0])?;
+    assert_close(&x, &tensor![1.0, 1.0]);
+
+    let p = linalg::pinv(&a)?;
+    assert_eq!(p.get_shape(), &[2, 4]);
+    assert_close(&a.matmul(&p)?.matmul(&a)?, &a);
+
+    let deficient = tensor![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    assert_eq!(
+        linalg::lstsq(&deficient, &tensor![1.0, 2.0, 3.0]).unwrap_err(),
+        TensorError::Singular
+    );
+    Ok(())
+}
+
+#[test]
+fn test_linalg_rejects_vectors() {
+    assert_eq!(
+        linalg::det(&tensor![1.0, 2.0]).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 2,
+            actual: 1
+        }
+    );
+}
+
+#[test]
+fn test_pinv_of_rank_deficient_matrix() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    let p = linalg::pinv(&a)?;
+    assert_close(&a.matmul(&p)?.matmul(&a)?, &a);
+    assert_close(&p.matmul(&a)?.matmul(&p)?, &p);
+    Ok(())
+}
+
+#[test]
+fn test_eigh_reconstructs_input() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[4.0, 1.0, 2.0], [1.0, 3.0, 0.0], [2.0, 0.0, 5.0]];
+    let eig = linalg::eigh(&a)?;
+    let (w, v) = (&eig.eigenvalues, &eig.eigenvectors);
+    assert_eq!(w.get_shape(), &[3]);
+    assert!(w.get(&[0])? <= w.get(&[1])? && w.get(&[1])? <= w.get(&[2])?);
+
+    // v · diag(w) · vᵀ, with the diagonal applied by broadcasting over columns.
+    let vt = v.transpose(0, 1)?;
+    assert_close(&(v * w).matmul(&vt)?, &a);
+    assert_close(
+        &vt.matmul(v)?,
+        &tensor![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    );
+
+    let diag = linalg::eigh(&tensor![[[2.0, 0.0], [0.0, -1.0]]])?;
+    assert_close(&diag.eigenvalues, &tensor![[-1.0, 2.0]]);
+    Ok(())
+}
+
+#[test]
+fn test_svd_reduced_and_full() -> Result<(), Box<dyn std::error::Error>> {
+    for a in [
+        tensor![[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]],
+        tensor![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]],
+        tensor![[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]],
+    ] {
+        let (m, n) = (a.get_shape()[0], a.get_shape()[1]);
+        let k = m.min(n);
+
+        let svd = linalg::svd(&a, false)?;
+        assert_eq!(svd.u.get_shape(), &[m, k]);
+        assert_eq!(svd.s.get_shape(), &[k]);
+        assert_eq!(svd.vt.get_shape(), &[k, n]);
+        assert_close(&(&svd.u * &svd.s).matmul(&svd.vt)?, &a);
+        assert!(svd.s.get(&[0])? >= svd.s.get(&[k - 1])?);
+
+        let full = linalg::svd(&a, true)?;
+        assert_eq!(full.u.get_shape(), &[m, m]);
+        assert_eq!(full.vt.get_shape(), &[n, n]);
+        assert_close(&full.u.transpose(0, 1)?.matmul(&full.u)?, &eye(m));
+        assert_close(&full.vt.matmul(&full.vt.transpose(0, 1)?)?, &eye(n));
+    }
+
+    let svd = linalg::svd(&tensor![[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]], false)?;
+    assert_close(&svd.s, &tensor![5.0, 3.0]);
+    Ok(())
+}
+
+#[test]
+fn test_rank_cond_and_norms() -> Result<(), Box<dyn std::error::Error>> {
+    let batch = tensor![[[1.0, 2.0], [2.0, 4.0]], [[1.0, 0.0], [0.0, 1.0]]];
+    assert_eq!(linalg::matrix_rank(&batch, None)?.get_data(), &[1, 2]);
+    assert_eq!(
+        linalg::matrix_rank(&tensor![[1.0, 0.0], [0.0, 1e-3]], Some(1e-2))?.get_data(),
+        &[1]
+    );
+
+    let c = linalg::cond(&tensor![[2.0f64, 0.0], [0.0, 0.5]])?;
+    assert!((c.get(&[])? - 4.0).abs() < 1e-9);
+    assert_eq!(
+        linalg::cond(&tensor![[1.0, 2.0], [2.0, 4.0]])?.get(&[])?,
+        &f64::INFINITY
+    );
+
+    use linalg::MatrixNorm;
+    let a = tensor![[1.0, -2.0], [-3.0, 4.0]];
+    let norm = |ord| -> Result<f64, TensorError> { Ok(*linalg::norm(&a, ord)?.get(&[])?) };
+    assert!((norm(MatrixNorm::Fro)? - 30.0f64.sqrt()).abs() < 1e-12);
+    assert_eq!(norm(MatrixNorm::One)?, 6.0);
+    assert_eq!(norm(MatrixNorm::Inf)?, 7.0);
+
+    let s = linalg::svd(&a, false)?.s;
+    assert!((norm(MatrixNorm::Two)? - s.get(&[0])?).abs() < 1e-12);
+    assert!((norm(MatrixNorm::Nuc)? - (s.get(&[0])? + s.get(&[1])?)).abs() < 1e-12);
+    Ok(())
+}
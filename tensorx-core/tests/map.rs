@@ -0,0 +1,74 @@
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_map_respects_strides() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6]].transpose(0, 1)?;
+
+    let doubled = t.map(|&x| x * 2);
+    assert_eq!(doubled.get_shape(), &[3, 2]);
+    assert_eq!(doubled.get_data(), &[2, 8, 4, 10, 6, 12]);
+
+    let labels: Tensor<String> = t.map(|x| format!("#{x}"));
+    assert_eq!(labels.get(&[0, 1])?, "#4");
+
+    let mut m = t.clone();
+    m.map_inplace(|x| *x -= 1);
+    assert_eq!(
+        m.iter().copied().collect::<Vec<_>>(),
+        vec![0, 3, 1, 4, 2, 5]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_zip_with_broadcasts() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[1.0f32, 2.0], [3.0, 4.0]];
+    let mask = tensor![true, false];
+
+    let masked = a.zip_with(&mask, |&x, &keep| if keep { x } else { 0.0 })?;
+    assert_eq!(masked.get_data(), &[1.0, 0.0, 3.0, 0.0]);
+
+    let lo = tensor![[0.0f32], [3.5]];
+    let hi = tensor![1.5f32];
+    let clamped = a.zip3_with(&lo, &hi, |&x, &l, &h| x.max(l).min(h))?;
+    assert_eq!(clamped.get_data(), &[1.0, 1.5, 1.5, 1.5]);
+
+    assert!(a.zip_with(&tensor![1, 2, 3], |x, _| *x).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_fold_axis_and_sum() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6]];
+
+    let rows = t.fold_axis(1, 0, |acc, &x| acc + x)?;
+    assert_eq!(rows.get_shape(), &[2]);
+    assert_eq!(rows.get_data(), &[6, 15]);
+
+    let max_cols = t.fold_axis(0, i32::MIN, |acc, &x| acc.max(x))?;
+    assert_eq!(max_cols.get_data(), &[4, 5, 6]);
+
+    let summed = t.sum(Some(0))?;
+    assert_eq!(summed.get_shape(), &[1, 3]);
+    assert_eq!(summed.get_data(), &[5, 7, 9]);
+    assert_eq!(t.sum(None)?.get_data(), &[21]);
+
+    assert!(t.fold_axis(2, 0, |acc, _| acc).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_float_ops_are_generic() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![-1.0f64, 0.0, 4.0];
+
+    assert_eq!(t.relu()?.get_data(), &[0.0, 0.0, 4.0]);
+    assert_eq!(t.sigmoid()?.get(&[1])?, &0.5);
+    assert_eq!(t.map(|x| x.abs()).sqrt()?.get_data(), &[1.0, 0.0, 2.0]);
+    assert_eq!(t.exp()?.log()?.get(&[1])?, &0.0);
+    assert_eq!(t.powf(2.0)?.get_data(), &[1.0, 0.0, 16.0]);
+
+    let f = tensor![[0.0f32, 1.0]].transpose(0, 1)?;
+    assert_eq!(f.tanh()?.get_shape(), &[2, 1]);
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use tensorx_core::assert_tensor_close;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_trig_and_hyperbolic() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![0.0, 0.5, -1.0];
+    let expect = |f: fn(f64) -> f64| Tensor::from([f(0.0), f(0.5), f(-1.0)]);
+    assert_tensor_close!(t.sin()?, expect(f64::sin));
+    assert_tensor_close!(t.cos()?, expect(f64::cos));
+    assert_tensor_close!(t.tan()?, expect(f64::tan));
+    assert_tensor_close!(t.asin()?, expect(f64::asin));
+    assert_tensor_close!(t.acos()?, expect(f64::acos));
+    assert_tensor_close!(t.atan()?, expect(f64::atan));
+    assert_tensor_close!(t.sinh()?, expect(f64::sinh));
+    assert_tensor_close!(t.cosh()?, expect(f64::cosh));
+    assert_tensor_close!(t.asinh()?, expect(f64::asinh));
+    assert_tensor_close!(t.atanh()?, expect(f64::atanh));
+    assert_tensor_close!(
+        tensor![1.0f32, 2.0].acosh()?,
+        tensor![0.0f32, 2.0f32.acosh()]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rounding_and_sign() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![-1.5, -0.5, 0.0, 0.5, 2.7];
+    assert_eq!(t.floor()?.get_data(), &[-2.0, -1.0, 0.0, 0.0, 2.0]);
+    assert_eq!(t.ceil()?.get_data(), &[-1.0, -0.0, 0.0, 1.0, 3.0]);
+    assert_eq!(t.round()?.get_data(), &[-2.0, -1.0, 0.0, 1.0, 3.0]);
+    assert_eq!(t.trunc()?.get_data(), &[-1.0, -0.0, 0.0, 0.0, 2.0]);
+    assert_eq!(t.abs()?.get_data(), &[1.5, 0.5, 0.0, 0.5, 2.7]);
+    assert_eq!(t.sign()?.get_data(), &[-1.0, -1.0, 0.0, 1.0, 1.0]);
+    assert!(tensor![f64::NAN].sign()?.get_data()[0].is_nan());
+
+    let clamped = tensor![-3.0, 0.5, f64::NAN, 7.0].clamp(-1.0, 1.0)?;
+    assert_eq!(clamped.get_data()[..2], [-1.0, 0.5]);
+    assert!(clamped.get_data()[2].is_nan());
+    assert_eq!(clamped.get_data()[3], 1.0);
+    Ok(())
+}
+
+#[test]
+fn test_logs_and_exponentials() -> Result<(), Box<dyn std::error::Error>> {
+    let tiny = tensor![1e-20, -1e-20];
+    assert_eq!(tiny.log1p()?.get_data(), &[1e-20, -1e-20]);
+    assert_eq!(tiny.expm1()?.get_data(), &[1e-20, -1e-20]);
+
+    let t = tensor![1.0, 8.0, 100.0];
+    assert_tensor_close!(t.log2()?, tensor![0.0, 3.0, 100f64.log2()]);
+    assert_tensor_close!(t.log10()?, tensor![0.0, 8f64.log10(), 2.0]);
+    assert_tensor_close!(tensor![4.0, 0.25].rsqrt()?, tensor![0.5, 2.0]);
+    Ok(())
+}
+
+#[test]
+fn test_activations() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![-2.0, 0.0, 3.0];
+    assert_eq!(t.leaky_relu(0.1)?.get_data(), &[-0.2, 0.0, 3.0]);
+    assert_tensor_close!(t.elu(1.0)?, tensor![(-2f64).exp_m1(), 0.0, 3.0]);
+    assert_tensor_close!(
+        t.selu()?,
+        tensor![
+            1.0507009873554805 * 1.6732632423543772 * (-2f64).exp_m1(),
+            0.0,
+            1.0507009873554805 * 3.0
+        ]
+    );
+    assert_eq!(t.hardtanh(-1.0, 1.0)?.get_data(), &[-1.0, 0.0, 1.0]);
+    assert_tensor_close!(t.softsign()?, tensor![-2.0 / 3.0, 0.0, 0.75]);
+
+    let sigmoid = |x: f64| 1.0 / (1.0 + (-x).exp());
+    assert_tensor_close!(
+        t.silu()?,
+        tensor![-2.0 * sigmoid(-2.0), 0.0, 3.0 * sigmoid(3.0)]
+    );
+    assert_tensor_close!(
+        t.swish(2.0)?,
+        tensor![-2.0 * sigmoid(-4.0), 0.0, 3.0 * sigmoid(6.0)]
+    );
+
+    let softplus = |x: f64| (1.0 + x.exp()).ln();
+    assert_tensor_close!(
+        t.softplus()?,
+        tensor![softplus(-2.0), 2f64.ln(), softplus(3.0)]
+    );
+    assert_tensor_close!(
+        t.mish()?,
+        tensor![
+            -2.0 * softplus(-2.0).tanh(),
+            0.0,
+            3.0 * softplus(3.0).tanh()
+        ]
+    );
+    // Large inputs neither overflow nor lose the linear regime.
+    assert_eq!(tensor![1000.0].softplus()?.get_data(), &[1000.0]);
+    assert_eq!(tensor![-1000.0].softplus()?.get_data(), &[0.0]);
+    Ok(())
+}
+
+#[test]
+fn test_gelu() -> Result<(), Box<dyn std::error::Error>> {
+    // Reference values of x · Φ(x).
+    let t = tensor![-3.0, -1.0, 0.0, 0.5, 2.0, 4.0];
+    let exact = tensor![
+        -0.00404969409489031,
+        -0.15865525393145707,
+        0.0,
+        0.34573123063700656,
+        1.9544997361036416,
+        3.9998733150326675
+    ];
+    assert_tensor_close!(t.gelu(false)?, exact, rtol = 1e-12, atol = 1e-15);
+    assert_tensor_close!(t.gelu(true)?, exact, rtol = 0.0, atol = 1e-3);
+    assert_tensor_close!(
+        tensor![-1.0f32, 2.0].gelu(false)?,
+        tensor![-0.15865525f32, 1.9544997]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unary_ops_respect_strides() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[0.0, 1.0], [-1.0, 4.0]].transpose(0, 1)?;
+    let relu = t.leaky_relu(0.5)?;
+    assert_eq!(relu.get_shape(), &[2, 2]);
+    assert_eq!(relu.get_data(), &[0.0, -0.5, 1.0, 4.0]);
+    assert_eq!(t.abs()?.get_data(), &[0.0, 1.0, 1.0, 4.0]);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use std::alloc::Layout;
+use std::ptr::NonNull;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tensorx_core::memory::{
+    ALIGNMENT, AlignedVec, SystemAllocator, TensorAllocator, memory_stats, reset_peak_bytes,
+    set_allocator,
+};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+// The counters and the allocator are process-wide, so tests in this file must
+// not run concurrently.
+static GLOBAL: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_storage_is_aligned() -> Result<(), Box<dyn std::error::Error>> {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    let bytes = tensor![1u8, 2, 3];
+    assert_eq!(bytes.get_data().as_ptr() as usize % ALIGNMENT, 0);
+
+    let t = Tensor::from_data(vec![3, 5], (0..15).map(f64::from).collect())?;
+    let sum = t.map(|x| x + 1.0);
+    let product = t.matmul(&t.transpose(0, 1)?)?;
+    for data in [t.get_data(), sum.get_data(), product.get_data()] {
+        assert_eq!(data.as_ptr() as usize % ALIGNMENT, 0);
+    }
+    assert_eq!(sum.get(&[2, 4])?, &15.0);
+    Ok(())
+}
+
+#[test]
+fn test_aligned_vec() {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    let mut v: AlignedVec<String> = AlignedVec::new();
+    assert_eq!(v.capacity(), 0);
+    for i in 0..100 {
+        v.push(i.to_string());
+    }
+    assert_eq!(v.len(), 100);
+    assert!(v.capacity() >= 100);
+    assert_eq!(v[42], "42");
+    assert_eq!(v.clone(), v);
+    assert_eq!(v.into_vec().last().map(String::as_str), Some("99"));
+
+    let moved = AlignedVec::from(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(moved.as_ptr() as usize % ALIGNMENT, 0);
+    assert_eq!(*moved, ["a", "b"]);
+
+    let units: AlignedVec<()> = std::iter::repeat_n((), 10).collect();
+    assert_eq!(units.len(), 10);
+}
+
+#[test]
+fn test_memory_stats() -> Result<(), Box<dyn std::error::Error>> {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    reset_peak_bytes();
+    let before = memory_stats();
+
+    let t = Tensor::new(vec![64, 64], 1.0f32);
+    let during = memory_stats();
+    assert_eq!(during.live_bytes - before.live_bytes, 64 * 64 * 4);
+    assert_eq!(during.allocations - before.allocations, 1);
+
+    let u = t.map(|x| x * 2.0);
+    assert_eq!(
+        memory_stats().live_bytes - before.live_bytes,
+        2 * 64 * 64 * 4
+    );
+    drop(t);
+    drop(u);
+
+    let after = memory_stats();
+    assert_eq!(after.live_bytes, before.live_bytes);
+    assert!(after.peak_bytes >= before.live_bytes + 2 * 64 * 64 * 4);
+    reset_peak_bytes();
+    assert_eq!(memory_stats().peak_bytes, after.live_bytes);
+    Ok(())
+}
+
+struct CountingAllocator {
+    allocated: AtomicUsize,
+    freed: AtomicUsize,
+}
+
+unsafe impl TensorAllocator for CountingAllocator {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        self.allocated.fetch_add(1, Ordering::Relaxed);
+        SystemAllocator.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.freed.fetch_add(1, Ordering::Relaxed);
+        unsafe { SystemAllocator.deallocate(ptr, layout) }
+    }
+}
+
+static COUNTING: CountingAllocator = CountingAllocator {
+    allocated: AtomicUsize::new(0),
+    freed: AtomicUsize::new(0),
+};
+
+#[test]
+fn test_custom_allocator() -> Result<(), Box<dyn std::error::Error>> {
+    let _guard = GLOBAL.lock().unwrap_or_else(|e| e.into_inner());
+    let outside = tensor![1.0, 2.0, 3.0];
+
+    let previous = set_allocator(&COUNTING);
+    let inside = &outside + 1.0f64;
+    set_allocator(previous);
+    assert_eq!(COUNTING.allocated.load(Ordering::Relaxed), 1);
+
+    // Buffers go back to the allocator that created them.
+    drop(outside);
+    assert_eq!(COUNTING.freed.load(Ordering::Relaxed), 0);
+    assert_eq!(inside.get_data(), &[2.0, 3.0, 4.0]);
+    drop(inside);
+    assert_eq!(COUNTING.freed.load(Ordering::Relaxed), 1);
+    Ok(())
+}
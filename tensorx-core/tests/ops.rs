@@ -1,3 +1,4 @@
+use tensorx_core::tensor;
 use tensorx_core::tensor::Tensor;
 
 #[test]
@@ -37,3 +38,65 @@ fn test_sub() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_reference_and_broadcast_ops() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let row = tensor![10.0, 20.0, 30.0];
+    let col = tensor![[1.0], [2.0]];
+
+    let sum = &a + &row;
+    assert_eq!(sum.get_shape(), &[2, 3]);
+    assert_eq!(sum.get_data(), &[11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+
+    let scaled = &a * &col;
+    assert_eq!(scaled.get_data(), &[1.0, 2.0, 3.0, 8.0, 10.0, 12.0]);
+
+    let outer = &col * &row;
+    assert_eq!(outer.get_shape(), &[2, 3]);
+    assert_eq!(outer.get_data(), &[10.0, 20.0, 30.0, 20.0, 40.0, 60.0]);
+
+    // Operands are still usable after reference forms.
+    assert_eq!(
+        (a.clone() - row).get_data(),
+        &[-9.0, -18.0, -27.0, -6.0, -15.0, -24.0]
+    );
+
+    let transposed = a.transpose(0, 1)?;
+    let diff = &transposed - &transposed.contiguous();
+    assert!(diff.get_data().iter().all(|&x| x == 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_scalar_ops_and_neg() {
+    let a = tensor![1i32, 2, 3];
+
+    assert_eq!((&a * 2).get_data(), &[2, 4, 6]);
+    assert_eq!((10 - &a).get_data(), &[9, 8, 7]);
+    assert_eq!((a.clone() + 1).get_data(), &[2, 3, 4]);
+    assert_eq!((12 / a.clone()).get_data(), &[12, 6, 4]);
+    assert_eq!((-&a).get_data(), &[-1, -2, -3]);
+    assert_eq!((-a).get_data(), &[-1, -2, -3]);
+}
+
+#[test]
+fn test_assign_ops_reuse_storage() {
+    let mut a = tensor![[1.0f32, 2.0], [3.0, 4.0]];
+    let ptr = a.get_data().as_ptr();
+
+    a += &tensor![1.0, 1.0];
+    a *= 2.0;
+    a -= tensor![[1.0], [2.0]];
+    a /= &tensor![[1.0, 2.0], [1.0, 2.0]];
+
+    assert_eq!(a.get_data(), &[3.0, 2.5, 6.0, 4.0]);
+    assert_eq!(a.get_data().as_ptr(), ptr);
+}
+
+#[test]
+#[should_panic(expected = "cannot be broadcast")]
+fn test_assign_op_cannot_grow_lhs() {
+    let mut a = tensor![1, 2];
+    a += tensor![[1, 2], [3, 4]];
+}
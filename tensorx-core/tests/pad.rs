@@ -0,0 +1,108 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::pad::PadMode;
+use tensorx_core::tensor;
+
+#[test]
+fn test_pad_modes_1d() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![1, 2, 3];
+    let pad = [(2, 3)];
+    assert_eq!(
+        t.pad(&pad, PadMode::Constant(0))?.get_data(),
+        &[0, 0, 1, 2, 3, 0, 0, 0]
+    );
+    assert_eq!(
+        t.pad(&pad, PadMode::Reflect)?.get_data(),
+        &[3, 2, 1, 2, 3, 2, 1, 2]
+    );
+    assert_eq!(
+        t.pad(&pad, PadMode::Replicate)?.get_data(),
+        &[1, 1, 1, 2, 3, 3, 3, 3]
+    );
+    assert_eq!(
+        t.pad(&pad, PadMode::Circular)?.get_data(),
+        &[2, 3, 1, 2, 3, 1, 2, 3]
+    );
+
+    // Padding wider than the axis keeps cycling.
+    assert_eq!(
+        t.pad(&[(5, 0)], PadMode::Reflect)?.get_data(),
+        &[2, 1, 2, 3, 2, 1, 2, 3]
+    );
+    assert_eq!(
+        tensor![7].pad(&[(1, 2)], PadMode::Reflect)?.get_data(),
+        &[7, 7, 7, 7]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pad_2d_and_strided() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2], [3, 4]];
+    let padded = t.pad(&[(1, 0), (0, 1)], PadMode::Constant(9))?;
+    assert_eq!(padded.get_shape(), &[3, 3]);
+    assert_eq!(padded.get_data(), &[9, 9, 9, 1, 2, 9, 3, 4, 9]);
+
+    let replicated = t.pad(&[(1, 1), (1, 1)], PadMode::Replicate)?;
+    assert_eq!(replicated.get_shape(), &[4, 4]);
+    assert_eq!(
+        replicated.get_data(),
+        &[1, 1, 2, 2, 1, 1, 2, 2, 3, 3, 4, 4, 3, 3, 4, 4]
+    );
+
+    // Transposed input is read in logical order.
+    let transposed = t
+        .transpose(0, 1)?
+        .pad(&[(0, 0), (1, 0)], PadMode::Circular)?;
+    assert_eq!(transposed.get_data(), &[3, 1, 3, 4, 2, 4]);
+
+    // Axes left at (0, 0) are untouched.
+    let rows = t.pad(&[(0, 0), (1, 1)], PadMode::Reflect)?;
+    assert_eq!(rows.get_data(), &[2, 1, 2, 1, 4, 3, 4, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_negative_padding_crops() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let cropped = t.pad(&[(-1, 0), (-1, -1)], PadMode::Constant(0))?;
+    assert_eq!(cropped.get_shape(), &[2, 1]);
+    assert_eq!(cropped.get_data(), &[5, 8]);
+
+    // Cropping one end while padding the other rotates circularly.
+    let rolled = tensor![1, 2, 3].pad(&[(-1, 1)], PadMode::Circular)?;
+    assert_eq!(rolled.get_data(), &[2, 3, 1]);
+
+    let emptied = tensor![1, 2].pad(&[(-1, -1)], PadMode::Reflect)?;
+    assert_eq!(emptied.get_shape(), &[0]);
+    Ok(())
+}
+
+#[test]
+fn test_pad_errors() {
+    let t = tensor![[1.0, 2.0]];
+    assert_eq!(
+        t.pad(&[(1, 1)], PadMode::Constant(0.0)).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 2,
+            actual: 1
+        }
+    );
+    assert!(matches!(
+        t.pad(&[(0, 0), (-2, -1)], PadMode::Replicate).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    let empty = tensor![1.0]
+        .pad(&[(-1, 0)], PadMode::Constant(0.0))
+        .unwrap();
+    assert!(matches!(
+        empty.pad(&[(1, 0)], PadMode::Reflect).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert_eq!(
+        empty
+            .pad(&[(1, 1)], PadMode::Constant(5.0))
+            .unwrap()
+            .get_data(),
+        &[5.0, 5.0]
+    );
+}
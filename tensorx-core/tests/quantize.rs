@@ -0,0 +1,155 @@
+use tensorx_core::assert_tensor_close;
+use tensorx_core::error::TensorError;
+use tensorx_core::quantize::{Calibration, Granularity, QuantParams, QuantizedTensor};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_per_tensor_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![-1.0f32, 0.0, 1.0, 3.0];
+    let params = QuantParams::calibrate::<i8>(&t, Granularity::PerTensor, Calibration::MinMax)?;
+    assert!((params.scale()[0] - 4.0 / 255.0).abs() < 1e-7);
+    assert_eq!(params.zero_point(), &[-64]);
+
+    let q = QuantizedTensor::<i8>::quantize(&t, params.clone())?;
+    assert_eq!(q.values().get_data(), &[-128, -64, 0, 127]);
+    let scale = params.scale()[0];
+    assert_tensor_close!(q.dequantize()?, t, rtol = 0.0, atol = scale / 2.0);
+
+    // Zero is always exactly representable, and out-of-range values saturate.
+    let outside = QuantizedTensor::<i8>::quantize(&tensor![0.0f32, 10.0, -10.0], params)?;
+    assert_eq!(outside.values().get_data(), &[-64, 127, -128]);
+    assert_eq!(outside.dequantize()?.get_data()[0], 0.0);
+
+    let unsigned = QuantParams::calibrate::<u8>(&t, Granularity::PerTensor, Calibration::MinMax)?;
+    assert_eq!(unsigned.zero_point(), &[64]);
+    let q = QuantizedTensor::<u8>::quantize(&t, unsigned)?;
+    assert_eq!(q.values().get_data(), &[0, 64, 128, 255]);
+    Ok(())
+}
+
+#[test]
+fn test_per_axis() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[0.0f32, 1.0], [-1.0, 3.0]];
+    let params = QuantParams::calibrate::<i8>(&t, Granularity::PerAxis(0), Calibration::MinMax)?;
+    assert_eq!(params.granularity(), Granularity::PerAxis(0));
+    assert_eq!(params.zero_point(), &[-128, -64]);
+    assert!(params.scale()[1] > 3.0 * params.scale()[0]);
+
+    let q = QuantizedTensor::<i8>::quantize(&t, params)?;
+    assert_eq!(q.values().get_data(), &[-128, 127, -128, 127]);
+    assert_tensor_close!(q.dequantize()?, t, rtol = 0.0, atol = 1e-2);
+
+    // Parameters given per column apply along the last axis.
+    let columns = QuantParams::per_axis(1, vec![0.5, 0.25], vec![0, 10])?;
+    let q = QuantizedTensor::<i8>::quantize(&t, columns)?;
+    assert_eq!(q.values().get_data(), &[0, 14, -2, 22]);
+    Ok(())
+}
+
+#[test]
+fn test_percentile_calibration_clips_outliers() -> Result<(), Box<dyn std::error::Error>> {
+    let mut data: Vec<f32> = (0..100).map(|i| i as f32).collect();
+    data.push(1000.0);
+    let t = Tensor::from_data(vec![101], data)?;
+
+    let minmax = QuantParams::calibrate::<u8>(&t, Granularity::PerTensor, Calibration::MinMax)?;
+    let clipped =
+        QuantParams::calibrate::<u8>(&t, Granularity::PerTensor, Calibration::Percentile(99.0))?;
+    assert!((minmax.scale()[0] - 1000.0 / 255.0).abs() < 1e-4);
+    assert!((clipped.scale()[0] - 99.0 / 255.0).abs() < 1e-6);
+
+    let q = QuantizedTensor::<u8>::quantize(&t, clipped)?;
+    assert_eq!(q.values().get_data()[100], 255);
+
+    assert!(matches!(
+        QuantParams::calibrate::<u8>(&t, Granularity::PerTensor, Calibration::Percentile(30.0))
+            .unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_quantized_matmul() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[1.0f32, -2.0], [0.5, 3.0]];
+    let b = tensor![[2.0f32, 0.0, -1.0], [1.0, 1.0, 1.0]];
+    let expected = a.matmul(&b)?;
+
+    let qa = QuantizedTensor::<i8>::quantize(
+        &a,
+        QuantParams::calibrate::<i8>(&a, Granularity::PerAxis(0), Calibration::MinMax)?,
+    )?;
+    let qb = QuantizedTensor::<u8>::quantize(
+        &b,
+        QuantParams::calibrate::<u8>(&b, Granularity::PerAxis(1), Calibration::MinMax)?,
+    )?;
+    let output =
+        QuantParams::calibrate::<i8>(&expected, Granularity::PerTensor, Calibration::MinMax)?;
+    let product = qa.matmul(&qb, output)?;
+    assert_eq!(product.get_shape(), &[2, 3]);
+    assert_tensor_close!(product.dequantize()?, expected, rtol = 0.0, atol = 0.05);
+    Ok(())
+}
+
+#[test]
+fn test_quantized_matmul_accumulates_in_i32() -> Result<(), Box<dyn std::error::Error>> {
+    // 512 products of 255 · 255 overflow i16 but not i32.
+    let params = QuantParams::per_tensor(1.0 / 255.0, -128);
+    let a = QuantizedTensor::<i8>::quantize(&Tensor::new(vec![1, 512], 1.0), params.clone())?;
+    let b = QuantizedTensor::<i8>::quantize(&Tensor::new(vec![512, 1], 1.0), params)?;
+    let product = a.matmul(&b, QuantParams::per_tensor(4.0, -128))?;
+    assert_eq!(product.values().get_data(), &[0]);
+    assert_eq!(product.dequantize()?.get_data(), &[512.0]);
+
+    // Past 33025 products of 255 · 255 the i32 accumulator could overflow.
+    let params = QuantParams::per_tensor(1.0, 0);
+    let output = QuantParams::per_tensor(1.0, 0);
+    for (k, ok) in [(33025, true), (33026, false)] {
+        let a = QuantizedTensor::<u8>::quantize(&Tensor::new(vec![1, k], 1.0), params.clone())?;
+        let b = QuantizedTensor::<i8>::quantize(&Tensor::new(vec![k, 1], 1.0), params.clone())?;
+        let product = a.matmul(&b, output.clone());
+        assert_eq!(product.is_ok(), ok);
+        if !ok {
+            assert!(matches!(
+                product.unwrap_err(),
+                TensorError::InvalidArgument(_)
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_quantization_errors() {
+    let t = tensor![[1.0f32, 2.0], [3.0, 4.0]];
+    let three = QuantParams::per_axis(0, vec![1.0; 3], vec![0; 3]).unwrap();
+    assert_eq!(
+        QuantizedTensor::<i8>::quantize(&t, three).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 2,
+            actual: 3
+        }
+    );
+    assert!(matches!(
+        QuantizedTensor::<i8>::quantize(&t, QuantParams::per_tensor(0.0, 0)).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert!(matches!(
+        QuantizedTensor::<u8>::quantize(&t, QuantParams::per_tensor(1.0, -1)).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert!(matches!(
+        QuantizedTensor::<i8>::quantize(&tensor![1.0, f32::NAN], QuantParams::per_tensor(1.0, 0))
+            .unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+
+    let rows = QuantParams::per_axis(1, vec![1.0; 2], vec![0; 2]).unwrap();
+    let lhs = QuantizedTensor::<i8>::quantize(&t, rows).unwrap();
+    assert!(matches!(
+        lhs.matmul(&lhs, QuantParams::per_tensor(1.0, 0))
+            .unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+}
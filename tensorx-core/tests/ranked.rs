@@ -0,0 +1,101 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::ranked::{RankedTensor, Tensor0, Tensor1, Tensor2, Tensor3};
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+#[test]
+fn test_conversions() -> Result<(), Box<dyn std::error::Error>> {
+    let m: Tensor2<i32> = tensor![[1, 2, 3], [4, 5, 6]].into_ranked()?;
+    assert_eq!(m.shape(), [2, 3]);
+    let ptr = m.as_dyn().get_data().as_ptr();
+
+    // Round trips move the tensor instead of copying it.
+    let t: Tensor<i32> = m.into();
+    assert_eq!(t.get_data().as_ptr(), ptr);
+    let m = Tensor2::try_from(t)?;
+    assert_eq!(m.into_dyn().get_data().as_ptr(), ptr);
+
+    assert_eq!(
+        Tensor3::try_from(tensor![1, 2]).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 3,
+            actual: 1
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_indexing() -> Result<(), Box<dyn std::error::Error>> {
+    let mut t = Tensor3::from_data([2, 3, 4], (0..24).collect())?;
+    assert_eq!(t[[1, 2, 3]], 23);
+    assert_eq!(t.get([0, 1, 2])?, &6);
+    t[[0, 0, 1]] = -1;
+    t.set([1, 0, 0], -2)?;
+    *t.get_mut([1, 1, 1])? += 100;
+    assert_eq!(&t.as_dyn().get_data()[..2], &[0, -1]);
+    assert_eq!(t[[1, 0, 0]], -2);
+    assert_eq!(t[[1, 1, 1]], 117);
+
+    let permuted = t.permute([2, 0, 1])?;
+    assert_eq!(permuted.shape(), [4, 2, 3]);
+    assert_eq!(permuted[[3, 1, 2]], 23);
+
+    assert!(matches!(
+        t.get([2, 0, 0]).unwrap_err(),
+        TensorError::IndexOutOfBounds { .. }
+    ));
+
+    let s = Tensor0::scalar(2.5);
+    assert!(s.shape().is_empty());
+    assert_eq!(s.into_scalar(), 2.5);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds_panics() {
+    let v = Tensor1::new([3], 0);
+    let _ = v[[3]];
+}
+
+#[test]
+fn test_products() -> Result<(), Box<dyn std::error::Error>> {
+    let a = Tensor2::from_data([2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])?;
+    let b = a.transpose(0, 1)?;
+    let product = a.matmul(&b)?;
+    assert_eq!(product.shape(), [2, 2]);
+    assert_eq!(
+        product.as_dyn().get_data(),
+        a.as_dyn().matmul(b.as_dyn())?.get_data()
+    );
+
+    let v = Tensor1::from_data([3], vec![1.0, 0.0, -1.0])?;
+    let w: Tensor1<f64> = a.matvec(&v)?;
+    assert_eq!(w.as_dyn().get_data(), &[-2.0, -2.0]);
+    assert_eq!(v.dot(&v)?, 2.0);
+
+    // The inner dimensions are still checked at run time.
+    assert_eq!(
+        a.matmul(&a).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 3,
+            actual: 2
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reshape_and_elementwise() -> Result<(), Box<dyn std::error::Error>> {
+    let v: RankedTensor<i32, 1> = Tensor1::from_data([6], (1..=6).collect())?;
+    let m: Tensor2<i32> = v.reshape([2, 3])?;
+    assert_eq!(m[[1, 0]], 4);
+
+    let row = Tensor2::from_data([1, 3], vec![10, 20, 30])?;
+    let sum = m.zip_with(&row, |a, b| a + b)?;
+    assert_eq!(sum.shape(), [2, 3]);
+    assert_eq!(sum.map(|x| x * 2)[[1, 2]], 72);
+    assert_eq!(sum.to_string(), sum.as_dyn().to_string());
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::tensor;
+
+#[test]
+fn test_sort_along_axis() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[3.0, 1.0, 2.0], [0.0, 5.0, -1.0]];
+    let (values, indices) = a.sort(1, false, true)?;
+    assert_eq!(values.get_data(), vec![1.0, 2.0, 3.0, -1.0, 0.0, 5.0]);
+    assert_eq!(indices.get_data(), vec![1, 2, 0, 2, 0, 1]);
+
+    let (values, indices) = a.sort(0, true, false)?;
+    assert_eq!(values.get_data(), vec![3.0, 5.0, 2.0, 0.0, 1.0, -1.0]);
+    assert_eq!(indices.get_data(), vec![0, 1, 0, 1, 0, 1]);
+
+    assert_eq!(
+        a.sort(2, false, true).unwrap_err(),
+        TensorError::InvalidAxis { axis: 2, rank: 2 }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sort_stability_and_nan() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![2, 1, 2, 1];
+    assert_eq!(a.argsort(0, false, true)?.get_data(), vec![1, 3, 0, 2]);
+    assert_eq!(a.argsort(0, true, true)?.get_data(), vec![0, 2, 1, 3]);
+
+    // NaN orders above infinity: last ascending, first descending.
+    let b = tensor![1.0, f64::NAN, f64::INFINITY, -1.0];
+    assert_eq!(b.argsort(0, false, true)?.get_data(), vec![3, 0, 2, 1]);
+    assert_eq!(b.argsort(0, true, true)?.get_data(), vec![1, 2, 0, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_topk() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[4.0, 9.0, 1.0, 7.0], [f64::NAN, 2.0, 3.0, 0.0]];
+    let (values, indices) = a.topk(2, 1)?;
+    assert_eq!(values.get_shape(), &[2, 2]);
+    let data = values.get_data();
+    assert_eq!(data[..2], [9.0, 7.0]);
+    assert!(data[2].is_nan());
+    assert_eq!(data[3], 3.0);
+    assert_eq!(indices.get_data(), vec![1, 3, 0, 2]);
+
+    assert_eq!(a.topk(0, 1)?.0.get_shape(), &[2, 0]);
+    assert_eq!(
+        a.topk(5, 1).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 4,
+            actual: 5
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_kthvalue_and_median() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[5, 1, 4, 2], [7, 3, 3, 9]];
+    let (values, indices) = a.kthvalue(2, 1)?;
+    assert_eq!(values.get_shape(), &[2, 1]);
+    assert_eq!(values.get_data(), vec![2, 3]);
+    assert_eq!(indices.get_data(), vec![3, 2]);
+
+    // Even lengths give the lower median.
+    let (values, indices) = a.median(1)?;
+    assert_eq!(values.get_data(), vec![2, 3]);
+    assert_eq!(indices.get_data(), vec![3, 2]);
+
+    let (values, _) = a.median(0)?;
+    assert_eq!(values.get_shape(), &[1, 4]);
+    assert_eq!(values.get_data(), vec![5, 1, 3, 2]);
+
+    assert_eq!(
+        a.kthvalue(0, 1).unwrap_err(),
+        TensorError::IndexOutOfBounds {
+            index: vec![0],
+            shape: vec![4]
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unique() -> Result<(), Box<dyn std::error::Error>> {
+    let a = tensor![[3, 1, 3], [2, 1, 3]];
+    let unique = a.unique();
+    assert_eq!(unique.values.get_data(), vec![1, 2, 3]);
+    assert_eq!(unique.counts.get_data(), vec![2, 1, 3]);
+    assert_eq!(unique.inverse.get_shape(), &[2, 3]);
+    assert_eq!(unique.inverse.get_data(), vec![2, 0, 2, 1, 0, 2]);
+
+    // All NaNs collapse into a single trailing entry.
+    let b = tensor![f64::NAN, 1.0, f64::NAN].unique();
+    assert_eq!(b.values.get_shape(), &[2]);
+    assert!(b.values.get_data()[1].is_nan());
+    assert_eq!(b.counts.get_data(), vec![1, 2]);
+    assert_eq!(b.inverse.get_data(), vec![1, 0, 1]);
+    Ok(())
+}
+
+#[test]
+fn test_searchsorted() -> Result<(), Box<dyn std::error::Error>> {
+    let sorted = tensor![1.0, 2.0, 2.0, 4.0, f64::NAN];
+    let values = tensor![[0.0, 2.0], [3.0, f64::NAN]];
+    let left = sorted.searchsorted(&values, false)?;
+    assert_eq!(left.get_shape(), &[2, 2]);
+    assert_eq!(left.get_data(), vec![0, 1, 3, 4]);
+    assert_eq!(
+        sorted.searchsorted(&values, true)?.get_data(),
+        vec![0, 3, 3, 5]
+    );
+
+    assert_eq!(
+        tensor![[1.0]].searchsorted(&values, false).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 1,
+            actual: 2
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::sparse::{SparseLayout, SparseTensor};
+use tensorx_core::tensor;
+
+#[test]
+fn test_dense_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let dense = tensor![[0.0, 2.0, 0.0], [0.0, 0.0, 0.0], [1.0, 0.0, 3.0]];
+    for layout in [SparseLayout::Coo, SparseLayout::Csr] {
+        let sparse = SparseTensor::from_dense(&dense, layout)?;
+        assert_eq!(sparse.layout(), layout);
+        assert_eq!(sparse.get_shape(), &[3, 3]);
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.values(), &[2.0, 1.0, 3.0]);
+        assert_eq!(sparse.to_dense().get_data(), dense.get_data());
+    }
+
+    let csr = SparseTensor::from_dense(&dense, SparseLayout::Csr)?;
+    let entries: Vec<_> = csr.iter().map(|(r, c, &v)| (r, c, v)).collect();
+    assert_eq!(entries, vec![(0, 1, 2.0), (2, 0, 1.0), (2, 2, 3.0)]);
+    Ok(())
+}
+
+#[test]
+fn test_coalesce_and_conversion() -> Result<(), Box<dyn std::error::Error>> {
+    let coo = SparseTensor::from_coo([2, 2], vec![1, 0, 1], vec![0, 1, 0], vec![1, 2, 3])?;
+    assert_eq!(coo.nnz(), 3);
+    // Duplicates add up when densified.
+    assert_eq!(coo.to_dense().get_data(), vec![0, 2, 4, 0]);
+
+    let coalesced = coo.coalesce();
+    assert_eq!(coalesced.layout(), SparseLayout::Coo);
+    let entries: Vec<_> = coalesced.iter().map(|(r, c, &v)| (r, c, v)).collect();
+    assert_eq!(entries, vec![(0, 1, 2), (1, 0, 4)]);
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.layout(), SparseLayout::Csr);
+    assert_eq!(csr.nnz(), 2);
+    assert_eq!(csr.to_coo().to_dense().get_data(), vec![0, 2, 4, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_construction_errors() {
+    assert_eq!(
+        SparseTensor::from_coo([2, 2], vec![0, 2], vec![0, 0], vec![1.0, 1.0]).unwrap_err(),
+        TensorError::IndexOutOfBounds {
+            index: vec![2, 0],
+            shape: vec![2, 2]
+        }
+    );
+    assert_eq!(
+        SparseTensor::from_coo([2, 2], vec![0], vec![0, 1], vec![1.0]).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 1,
+            actual: 2
+        }
+    );
+    assert!(matches!(
+        SparseTensor::from_csr([2, 2], vec![0, 2, 1], vec![0, 1], vec![1.0, 1.0]).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert_eq!(
+        SparseTensor::from_dense(&tensor![1.0, 0.0], SparseLayout::Coo).unwrap_err(),
+        TensorError::RankMismatch {
+            expected: 2,
+            actual: 1
+        }
+    );
+}
+
+#[test]
+fn test_transpose() -> Result<(), Box<dyn std::error::Error>> {
+    let dense = tensor![[1, 0, 2], [0, 3, 0]];
+    let expected = dense.transpose(0, 1)?.contiguous();
+    for layout in [SparseLayout::Coo, SparseLayout::Csr] {
+        let t = SparseTensor::from_dense(&dense, layout)?.transpose();
+        assert_eq!(t.layout(), layout);
+        assert_eq!(t.get_shape(), &[3, 2]);
+        assert_eq!(t.to_dense().get_data(), expected.get_data());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_spmm_and_spmv() -> Result<(), Box<dyn std::error::Error>> {
+    let dense = tensor![[1.0, 0.0, 2.0], [0.0, 0.0, 0.0], [0.0, 3.0, 0.0]];
+    let rhs = tensor![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let expected = dense.matmul(&rhs)?;
+    for layout in [SparseLayout::Coo, SparseLayout::Csr] {
+        let sparse = SparseTensor::from_dense(&dense, layout)?;
+        let product = sparse.spmm(&rhs)?;
+        assert_eq!(product.get_shape(), &[3, 2]);
+        assert_eq!(product.get_data(), expected.get_data());
+
+        // Strided dense operands are read in logical order.
+        let strided = sparse.spmm(&rhs.transpose(0, 1)?.contiguous().transpose(0, 1)?)?;
+        assert_eq!(strided.get_data(), expected.get_data());
+
+        let y = sparse.spmv(&tensor![1.0, 1.0, 1.0])?;
+        assert_eq!(y.get_data(), vec![3.0, 0.0, 3.0]);
+    }
+
+    let sparse = SparseTensor::from_dense(&dense, SparseLayout::Csr)?;
+    assert_eq!(
+        sparse.spmm(&tensor![[1.0], [2.0]]).unwrap_err(),
+        TensorError::DimensionalMismatch {
+            expected: 3,
+            actual: 2
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_elementwise_ops() -> Result<(), Box<dyn std::error::Error>> {
+    let a = SparseTensor::from_dense(&tensor![[1, 0], [2, 0]], SparseLayout::Csr)?;
+    let b = SparseTensor::from_dense(&tensor![[3, 4], [0, 0]], SparseLayout::Coo)?;
+
+    let sum = &a + &b;
+    assert_eq!(sum.layout(), SparseLayout::Csr);
+    assert_eq!(sum.nnz(), 3);
+    assert_eq!(sum.to_dense().get_data(), vec![4, 4, 2, 0]);
+
+    assert_eq!((&a - &b).to_dense().get_data(), vec![-2, -4, 2, 0]);
+
+    // Products only keep positions stored in both operands.
+    let product = &a * &b;
+    assert_eq!(product.nnz(), 1);
+    assert_eq!(product.to_dense().get_data(), vec![3, 0, 0, 0]);
+
+    let scaled = a.mul_dense(&tensor![10, 100])?;
+    assert_eq!(scaled.nnz(), 2);
+    assert_eq!(scaled.to_dense().get_data(), vec![10, 0, 20, 0]);
+
+    let doubled = a.map_values(|&x| f64::from(x) * 0.5);
+    assert_eq!(doubled.to_dense().get_data(), vec![0.5, 0.0, 1.0, 0.0]);
+
+    let other = SparseTensor::from_dense(&tensor![[1, 0, 0]], SparseLayout::Coo)?;
+    assert_eq!(
+        a.elementwise_add(&other).unwrap_err(),
+        TensorError::ShapeMismatch {
+            expected: vec![2, 2],
+            actual: vec![1, 3]
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use tensorx_core::assert_tensor_close;
+use tensorx_core::tensor;
+
+// Reference values below were computed to 30 significant digits and rounded
+// to the nearest f64.
+
+#[test]
+fn test_error_functions() -> Result<(), Box<dyn std::error::Error>> {
+    assert_tensor_close!(
+        tensor![-1.0, 0.0, 0.5, 2.0, 5.0].erf()?,
+        tensor![
+            -0.8427007929497149,
+            0.0,
+            0.5204998778130465,
+            0.9953222650189527,
+            0.9999999999984626
+        ],
+        rtol = 1e-15,
+        atol = 0.0
+    );
+    // The complement keeps its relative accuracy deep into the tail.
+    assert_tensor_close!(
+        tensor![-1.0, 0.5, 3.0, 10.0, 26.0].erfc()?,
+        tensor![
+            1.8427007929497148,
+            0.4795001221869535,
+            2.209049699858544e-05,
+            2.088487583762545e-45,
+            5.663192408856143e-296
+        ],
+        rtol = 1e-15,
+        atol = 0.0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_erfinv() -> Result<(), Box<dyn std::error::Error>> {
+    let y = tensor![0.0, 0.5, -0.9, 0.999999, 1.0 - 2f64.powi(-40)];
+    assert_tensor_close!(
+        y.erfinv()?,
+        tensor![
+            0.0,
+            0.4769362762044699,
+            -1.1630871536766743,
+            3.458910737275499,
+            5.05125408524939
+        ],
+        rtol = 1e-15,
+        atol = 0.0
+    );
+
+    let x = tensor![-2.0, -0.3, 0.1, 1.7];
+    assert_tensor_close!(x.erf()?.erfinv()?, x, rtol = 1e-14, atol = 0.0);
+
+    let edges = tensor![1.0, -1.0, 1.5].erfinv()?;
+    assert_eq!(edges.get_data()[..2], [f64::INFINITY, f64::NEG_INFINITY]);
+    assert!(edges.get_data()[2].is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_gamma_and_lgamma() -> Result<(), Box<dyn std::error::Error>> {
+    let sqrt_pi = std::f64::consts::PI.sqrt();
+    assert_tensor_close!(
+        tensor![0.5, 5.0, 0.1, -1.5, 7.5, 170.5].gamma()?,
+        tensor![
+            sqrt_pi,
+            24.0,
+            9.51350769866873,
+            2.363271801207355,
+            1871.2543057977884,
+            5.56209241456e305
+        ],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    let poles = tensor![0.0, -0.0, -2.0, 180.0].gamma()?;
+    assert_eq!(poles.get_data()[..2], [f64::INFINITY, f64::NEG_INFINITY]);
+    assert!(poles.get_data()[2].is_nan());
+    assert_eq!(poles.get_data()[3], f64::INFINITY);
+
+    assert_tensor_close!(
+        tensor![0.5, 1.0, 2.0, 100.0, 1e6, -2.5].lgamma()?,
+        tensor![
+            0.5723649429247001,
+            0.0,
+            0.0,
+            359.1342053695754,
+            12815504.569147611,
+            -0.056243716497674054
+        ],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_eq!(tensor![-3.0].lgamma()?.get_data(), &[f64::INFINITY]);
+
+    // Next to the roots at 1 and 2 the result is tiny, so the check is
+    // relative to it.
+    let eps = f64::EPSILON;
+    assert_tensor_close!(
+        tensor![
+            1.0 + 1e-10,
+            1.0 - 1e-10,
+            1.0 + eps,
+            1.0 - eps / 2.0,
+            2.0 + 1e-10,
+            2.0 - 1e-10,
+            2.0 + 2.0 * eps,
+            2.0 - eps,
+            0.7,
+            1.2,
+            1.5,
+            2.4
+        ]
+        .lgamma()?,
+        tensor![
+            -5.772157125783244e-11,
+            5.7721571274281786e-11,
+            -1.2816762426960008e-16,
+            6.408381213480008e-17,
+            4.2278437011204657e-11,
+            -4.2278437004755317e-11,
+            1.8775396131086244e-16,
+            -9.387698065543117e-17,
+            0.26086724653166654,
+            -0.08537409000331583,
+            -0.12078223763524522,
+            0.21685932244884157
+        ],
+        rtol = 4.0 * eps,
+        atol = 0.0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_digamma_and_polygamma() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![1.0, 0.5, 10.0, -0.5];
+    let digamma = tensor![
+        -0.5772156649015329,
+        -1.9635100260214235,
+        2.251752589066721,
+        0.03648997397857652
+    ];
+    assert_tensor_close!(t.digamma()?, digamma, rtol = 1e-14, atol = 0.0);
+    assert_tensor_close!(t.polygamma(0)?, digamma, rtol = 1e-14, atol = 0.0);
+    assert!(tensor![-1.0f64].digamma()?.get_data()[0].is_nan());
+
+    assert_tensor_close!(
+        tensor![1.0, 0.5, 30.0].polygamma(1)?,
+        tensor![1.6449340668482264, 4.934802200544679, 0.033895060357739946],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_tensor_close!(
+        tensor![1.0].polygamma(2)?,
+        tensor![-2.4041138063191885],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_tensor_close!(
+        tensor![2.5].polygamma(3)?,
+        tensor![0.22390584881725206],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+
+    // Negative arguments are reflected rather than shifted up one by one.
+    assert_tensor_close!(
+        tensor![-0.5, -1e8 - 0.5, -1e15 - 0.25].polygamma(1)?,
+        tensor![8.934802200544679, 9.869604391089359, 19.739208802178716],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_tensor_close!(
+        tensor![-1.25, -7.3, -123456789.75].polygamma(2)?,
+        tensor![123.72136678366236, 68.82117406359033, -124.02510672119928],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_tensor_close!(
+        tensor![-2.5].polygamma(3)?,
+        tensor![194.74787621918762],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    let huge = tensor![-(2.0f64).powi(60)].polygamma(1)?;
+    assert_eq!(huge.get_data(), &[f64::INFINITY]);
+    Ok(())
+}
+
+#[test]
+fn test_betainc() -> Result<(), Box<dyn std::error::Error>> {
+    let x = tensor![0.4, 0.2, 0.4, 0.99];
+    let a = tensor![2.0, 0.5, 50.0, 5.0];
+    let b = tensor![3.0, 0.5, 80.0, 0.3];
+    assert_tensor_close!(
+        x.betainc(&a, &b)?,
+        tensor![
+            0.5248,
+            0.2951672353008666,
+            0.6450659221505504,
+            0.560003727526935
+        ],
+        rtol = 1e-13,
+        atol = 0.0
+    );
+
+    // I_x(1, 1) = x, and the parameters broadcast against x.
+    let grid = tensor![[0.0, 0.25], [0.5, 1.0]];
+    let one = tensor![1.0];
+    assert_tensor_close!(grid.betainc(&one, &one)?, grid);
+    let symmetric = tensor![0.5].betainc(&tensor![0.5, 3.0, 40.0], &tensor![0.5, 3.0, 40.0])?;
+    assert_tensor_close!(symmetric, tensor![0.5, 0.5, 0.5], rtol = 1e-14, atol = 0.0);
+    assert!(tensor![0.5].betainc(&tensor![-1.0f64], &one)?.get_data()[0].is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_bessel_i0_i1() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![0.0, 1.0, -2.5, 10.0, 50.0];
+    assert_tensor_close!(
+        t.i0()?,
+        tensor![
+            1.0,
+            1.2660658777520084,
+            3.289839144050123,
+            2815.7166284662544,
+            2.9325537838493362e20
+        ],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    assert_tensor_close!(
+        t.i1()?,
+        tensor![
+            0.0,
+            0.565159103992485,
+            -2.5167162452886984,
+            2670.9883037012546,
+            2.903078590103557e20
+        ],
+        rtol = 1e-14,
+        atol = 0.0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_single_precision() -> Result<(), Box<dyn std::error::Error>> {
+    // f32 results are the correctly rounded f64 values.
+    let t = tensor![0.5f32, 1.5, 3.0];
+    assert_eq!(
+        t.erf()?.get_data(),
+        &[0.520_499_9f32, 0.966_105_16, 0.999_977_9]
+    );
+    assert_eq!(t.gamma()?.get_data(), &[1.772_453_9f32, 0.886_226_95, 2.0]);
+    assert_eq!(
+        t.lgamma()?.get_data(),
+        &[0.572_364_9f32, -0.120_782_24, std::f32::consts::LN_2]
+    );
+    assert_eq!(
+        t.i0()?.get_data(),
+        &[1.063_483_4f32, 1.646_723_2, 4.880_792_6]
+    );
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use tensorx_core::error::TensorError;
+use tensorx_core::stats::Interpolation;
+use tensorx_core::tensor;
+use tensorx_core::tensor::Tensor;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_mean_var_std() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![[1.0, 2.0, 3.0, 4.0], [2.0, 4.0, 6.0, 8.0]];
+    let mean = t.mean(Some(1))?;
+    assert_eq!(mean.get_shape(), &[2, 1]);
+    assert_close(mean.get_data(), &[2.5, 5.0]);
+    assert_close(t.mean(None)?.get_data(), &[3.75]);
+
+    assert_close(t.var(Some(1), 0)?.get_data(), &[1.25, 5.0]);
+    assert_close(t.var(Some(1), 1)?.get_data(), &[5.0 / 3.0, 20.0 / 3.0]);
+    assert_close(t.std(Some(0), 0)?.get_data(), &[0.5, 1.0, 1.5, 2.0]);
+
+    // Too few observations for the requested degrees of freedom.
+    assert!(t.var(Some(0), 2)?.get_data().iter().all(|x| x.is_nan()));
+    Ok(())
+}
+
+#[test]
+fn test_var_is_stable_for_large_offsets() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+    assert_close(t.var(None, 1)?.get_data(), &[30.0]);
+    Ok(())
+}
+
+#[test]
+fn test_quantile_interpolation() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![4.0, 1.0, 3.0, 2.0];
+    let q = |q, mode| t.quantile(q, None, mode).map(|r| r.get_data()[0]);
+    assert_eq!(q(0.5, Interpolation::Linear)?, 2.5);
+    assert_eq!(q(0.4, Interpolation::Lower)?, 2.0);
+    assert_eq!(q(0.4, Interpolation::Higher)?, 3.0);
+    assert_eq!(q(0.4, Interpolation::Nearest)?, 2.0);
+    assert_eq!(q(0.5, Interpolation::Nearest)?, 3.0);
+    assert_eq!(q(0.4, Interpolation::Midpoint)?, 2.5);
+    assert_eq!(q(1.0, Interpolation::Linear)?, 4.0);
+
+    let m = tensor![[1.0, 5.0, 3.0], [f64::NAN, 1.0, 2.0]];
+    let p = m.percentile(50.0, Some(1), Interpolation::Linear)?;
+    assert_eq!(p.get_shape(), &[2, 1]);
+    assert_eq!(p.get_data()[0], 3.0);
+    assert!(p.get_data()[1].is_nan());
+
+    assert!(matches!(
+        t.quantile(1.5, None, Interpolation::Linear).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_histogram() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![0.0, 0.5, 1.0, 1.5, 2.0, f64::NAN, 3.0];
+    let (counts, edges) = t.histogram(3, None)?;
+    assert_eq!(counts.get_data(), vec![2, 2, 2]);
+    assert_eq!(edges.get_data(), vec![0.0, 1.0, 2.0, 3.0]);
+
+    // Values outside an explicit range are dropped; the last bin is closed.
+    let (counts, _) = t.histogram(2, Some((0.5, 2.0)))?;
+    assert_eq!(counts.get_data(), vec![2, 2]);
+
+    let hist = t.histc(4, 0.0, 2.0)?;
+    assert_eq!(hist.get_data(), vec![1.0, 1.0, 1.0, 2.0]);
+
+    let (counts, edges) = tensor![2.0, 2.0].histogram(1, None)?;
+    assert_eq!(counts.get_data(), vec![2]);
+    assert_eq!(edges.get_data(), vec![1.5, 2.5]);
+
+    assert!(matches!(
+        t.histogram(0, None).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_bincount() -> Result<(), Box<dyn std::error::Error>> {
+    let t = tensor![1, 3, 1, 0];
+    assert_eq!(t.bincount(0)?.get_data(), vec![1, 2, 0, 1]);
+    assert_eq!(t.bincount(6)?.get_data(), vec![1, 2, 0, 1, 0, 0]);
+
+    let weights = tensor![0.5, 1.0, 0.25, 2.0];
+    assert_eq!(
+        t.bincount_weighted(&weights, 0)?.get_data(),
+        vec![2.0, 0.75, 0.0, 1.0]
+    );
+
+    assert!(matches!(
+        tensor![1, -1].bincount(0).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert!(matches!(
+        tensor![0u128, u128::MAX].bincount(0).unwrap_err(),
+        TensorError::InvalidArgument(_)
+    ));
+    assert_eq!(
+        Tensor::from_data(vec![0], Vec::<u8>::new())?
+            .bincount(2)?
+            .get_data(),
+        vec![0, 0]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cov_and_corrcoef() -> Result<(), Box<dyn std::error::Error>> {
+    // Two variables as rows, observations along axis 1.
+    let x = tensor![[0.0, 1.0, 2.0], [2.0, 1.0, 0.0]];
+    let cov = x.cov(1, 1)?;
+    assert_eq!(cov.get_shape(), &[2, 2]);
+    assert_close(cov.get_data(), &[1.0, -1.0, -1.0, 1.0]);
+
+    // The same data with observations as rows.
+    let cov_t = x.transpose(0, 1)?.cov(0, 1)?;
+    assert_close(cov_t.get_data(), cov.get_data());
+
+    let y = tensor![[1.0, 2.0, 4.0], [2.0, 4.0, 8.0], [3.0, 1.0, 3.0]];
+    let r = y.corrcoef(1)?;
+    let data = r.get_data();
+    assert_close(&[data[0], data[4], data[8]], &[1.0, 1.0, 1.0]);
+    assert_close(&[data[1], data[3]], &[1.0, 1.0]);
+
+    let single = tensor![1.0, 2.0, 3.0, 4.0].cov(0, 1)?;
+    assert_eq!(single.get_shape(), &[1, 1]);
+    assert_close(single.get_data(), &[5.0 / 3.0]);
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use tensorx_core::error::{Result, TensorError};
 use wgpu::{Device, Queue};
 
 pub struct GpuContext {
@@ -8,15 +9,16 @@ pub struct GpuContext {
 }
 
 impl GpuContext {
-    pub fn new() -> Self {
+    pub fn new() -> Result<Self> {
         let instance = wgpu::Instance::default();
-        let adapter = pollster::block_on(instance.request_adapter(&Default::default())).unwrap();
-        let (device, queue) =
-            pollster::block_on(adapter.request_device(&Default::default(), None)).unwrap();
+        let adapter = pollster::block_on(instance.request_adapter(&Default::default()))
+            .ok_or_else(|| TensorError::GpuError("no suitable GPU adapter found".into()))?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&Default::default(), None))
+            .map_err(|e| TensorError::GpuError(e.to_string()))?;
 
-        Self {
+        Ok(Self {
             device: Arc::new(device),
             queue: Arc::new(queue),
-        }
+        })
     }
 }
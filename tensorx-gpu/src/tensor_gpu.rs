@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use tensorx_core::error::{Result, TensorError};
 use tensorx_core::tensor::compute_strides;
 use wgpu::{Buffer, BufferUsages, util::DeviceExt};
 
@@ -21,19 +22,23 @@ impl<T> TensorGpu<T> {
         self.len
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub fn strides(&self) -> &[usize] {
         &self.strides
     }
 }
 
 impl<T: bytemuck::Pod> TensorGpu<T> {
-    pub fn to_vec(&self, ctx: &GpuContext) -> Vec<T> {
+    pub fn to_vec(&self, ctx: &GpuContext) -> Result<Vec<T>> {
         self.buffer.to_vec(ctx)
     }
 }
 
 impl<T: bytemuck::Pod> GpuBuffer<T> {
-    pub fn to_vec(&self, ctx: &GpuContext) -> Vec<T> {
+    pub fn to_vec(&self, ctx: &GpuContext) -> Result<Vec<T>> {
         let buffer_size = (self.len * std::mem::size_of::<T>()) as u64;
 
         let staging = ctx.device.create_buffer(&wgpu::BufferDescriptor {
@@ -55,10 +60,13 @@ impl<T: bytemuck::Pod> GpuBuffer<T> {
         let buffer_slice = staging.slice(..);
         let (tx, rx) = std::sync::mpsc::channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |r| {
-            tx.send(r).unwrap();
+            // The receiver only goes away if `to_vec` has already returned.
+            let _ = tx.send(r);
         });
         ctx.device.poll(wgpu::Maintain::Wait);
-        rx.recv().unwrap().unwrap();
+        rx.recv()
+            .map_err(|e| TensorError::GpuError(e.to_string()))?
+            .map_err(|e| TensorError::GpuError(e.to_string()))?;
 
         // Read the data
         let data = buffer_slice.get_mapped_range();
@@ -67,41 +75,55 @@ impl<T: bytemuck::Pod> GpuBuffer<T> {
         drop(data);
         staging.unmap();
 
-        result
+        Ok(result)
     }
 }
 
 impl TensorGpu<f32> {
-    pub fn from_data(ctx: &GpuContext, data: &[f32], shape: Vec<usize>) -> Self {
+    pub fn from_data(ctx: &GpuContext, data: &[f32], shape: Vec<usize>) -> Result<Self> {
         let len = shape.iter().product();
-        assert_eq!(data.len(), len);
+        if data.len() != len {
+            return Err(TensorError::DimensionalMismatch {
+                expected: len,
+                actual: data.len(),
+            });
+        }
         let strides = compute_strides(&shape); // from tensr-core
         let buffer = GpuBuffer::from_data(ctx, data);
-        Self {
+        Ok(Self {
             shape,
             strides,
             len,
             buffer,
-        }
+        })
     }
 
-    pub fn matmul(ctx: &GpuContext, a: &Self, b: &Self) -> Self {
-        assert_eq!(a.shape.len(), 2);
-        assert_eq!(b.shape.len(), 2);
+    pub fn matmul(ctx: &GpuContext, a: &Self, b: &Self) -> Result<Self> {
+        if let Some(t) = [a, b].into_iter().find(|t| t.shape.len() != 2) {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: t.shape.len(),
+            });
+        }
         let (m, k1) = (a.shape[0], a.shape[1]);
         let (k2, n) = (b.shape[0], b.shape[1]);
-        assert_eq!(k1, k2);
+        if k1 != k2 {
+            return Err(TensorError::DimensionalMismatch {
+                expected: k1,
+                actual: k2,
+            });
+        }
 
         let result = matmul_gpu(ctx, &a.buffer, &b.buffer, m, k1, n);
 
         let shape = vec![m, n];
         let strides = compute_strides(&shape);
-        Self {
+        Ok(Self {
             shape,
             strides,
             len: m * n,
             buffer: result,
-        }
+        })
     }
 }
 
@@ -1,16 +1,18 @@
+use tensorx_core::assert_tensor_close;
+use tensorx_core::tensor::Tensor;
 use tensorx_gpu::{context::GpuContext, tensor_gpu::TensorGpu};
 
 #[test]
-fn test_matmul_gpu() {
-    let ctx = GpuContext::new();
-    
+fn test_matmul_gpu() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = GpuContext::new()?;
+
     let a_data = vec![1.0, 2.0, 3.0, 4.0];
     let b_data = vec![5.0, 6.0, 7.0, 8.0];
 
-    let a = TensorGpu::from_data(&ctx, &a_data, vec![2, 2]);
-    let b = TensorGpu::from_data(&ctx, &b_data, vec![2, 2]);
-    let c = TensorGpu::matmul(&ctx, &a, &b);
-    let c_host = c.to_vec(&ctx);
+    let a = TensorGpu::from_data(&ctx, &a_data, vec![2, 2])?;
+    let b = TensorGpu::from_data(&ctx, &b_data, vec![2, 2])?;
+    let c = TensorGpu::matmul(&ctx, &a, &b)?;
+    let c_host = c.to_vec(&ctx)?;
 
     let expected = vec![
         1.0 * 5.0 + 2.0 * 7.0,
@@ -19,10 +21,12 @@ fn test_matmul_gpu() {
         3.0 * 6.0 + 4.0 * 8.0,
     ];
 
+    assert_tensor_close!(
+        Tensor::from_data(vec![2, 2], c_host)?,
+        Tensor::from_data(vec![2, 2], expected)?,
+        rtol = 1e-4,
+        atol = 1e-4
+    );
 
-    assert_eq!(expected, c_host);
-
-    // for (i, (x, y)) in c_host.iter().zip(expected.iter()).enumerate() {
-    //     assert!((x - y).abs() < 1e-4, "Mismatch at {}: {} != {}", i, x, y);
-    // }
+    Ok(())
 }
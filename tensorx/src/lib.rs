@@ -1,2 +1,6 @@
-pub use tensorx_core::{tensor::*, view::*};
+pub use tensorx_core::{
+    assert_tensor_close, blas, broadcast::*, complex::*, display::*, einsum::*, element::*, fft,
+    iter::*, lazy::*, linalg, memory::*, nested::*, pad::*, quantize::*, ranked::*, sort::*,
+    sparse::*, stats::*, tensor, tensor::*, view::*,
+};
 pub use tensorx_gpu::tensor_gpu::*;